@@ -0,0 +1,132 @@
+use std::alloc::Layout;
+
+use bevy::{
+    ecs::component::{ComponentDescriptor, ComponentId, StorageType},
+    prelude::*,
+    ptr::OwningPtr,
+};
+use bevy_trait_resource::{linear, trait_resource, TraitResourceExt};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [usize; 3] = [10, 100, 1000];
+
+#[trait_resource]
+pub trait BenchTrait {
+    fn increment(&mut self);
+}
+
+struct Counter {
+    value: u64,
+}
+
+impl BenchTrait for Counter {
+    fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+fn cast(ptr: *mut u8) -> *mut dyn BenchTrait {
+    ptr.cast::<Counter>() as *mut dyn BenchTrait
+}
+
+/// A world with `count` untyped resources of the layout of `Counter`, none of them registered.
+fn world_with_resources(count: usize) -> (World, Vec<ComponentId>) {
+    let mut world = World::new();
+    let ids = (0..count)
+        .map(|i| {
+            // SAFETY: Counter has no drop glue.
+            let id = world.init_component_with_descriptor(unsafe {
+                ComponentDescriptor::new_with_layout(format!("Counter{i}"), StorageType::Table, Layout::new::<Counter>(), None)
+            });
+            OwningPtr::make(Counter { value: 0 }, |ptr| unsafe { world.insert_resource_by_id(id, ptr) });
+            id
+        })
+        .collect();
+    (world, ids)
+}
+
+fn register_all(world: &mut World, ids: &[ComponentId]) {
+    for &id in ids {
+        // SAFETY: The resources have the layout of `Counter`.
+        unsafe { world.register_resource_by_id_as::<dyn BenchTrait>(id, cast) };
+    }
+}
+
+fn register_all_linear(world: &mut World, ids: &[ComponentId]) {
+    for &id in ids {
+        // SAFETY: The resources have the layout of `Counter`.
+        unsafe { linear::register_resource_by_id_as::<dyn BenchTrait>(world, id, cast) };
+    }
+}
+
+fn register(c: &mut Criterion) {
+    let mut group = c.benchmark_group("register");
+    for count in SIZES {
+        // Registering an already registered resource looks it up.
+        let (mut world, ids) = world_with_resources(count);
+        register_all(&mut world, &ids);
+        group.bench_with_input(BenchmarkId::new("indexed", count), &ids, |b, ids| b.iter(|| register_all(&mut world, ids)));
+
+        let (mut world, ids) = world_with_resources(count);
+        register_all_linear(&mut world, &ids);
+        group.bench_with_input(BenchmarkId::new("linear", count), &ids, |b, ids| b.iter(|| register_all_linear(&mut world, ids)));
+    }
+    group.finish();
+}
+
+fn unregister(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unregister_and_register");
+    for count in SIZES {
+        let (mut world, ids) = world_with_resources(count);
+        register_all(&mut world, &ids);
+        group.bench_with_input(BenchmarkId::new("indexed", count), &ids, |b, ids| {
+            b.iter(|| {
+                for &id in ids {
+                    world.unregister_resource_by_id_from_trait::<dyn BenchTrait>(id);
+                }
+                register_all(&mut world, ids);
+            })
+        });
+
+        let (mut world, ids) = world_with_resources(count);
+        register_all_linear(&mut world, &ids);
+        group.bench_with_input(BenchmarkId::new("linear", count), &ids, |b, ids| {
+            b.iter(|| {
+                for &id in ids {
+                    linear::unregister_resource_by_id_from_trait::<dyn BenchTrait>(&mut world, id);
+                }
+                register_all_linear(&mut world, ids);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn iterate_mut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate_mut");
+    for count in SIZES {
+        let (mut world, ids) = world_with_resources(count);
+        register_all(&mut world, &ids);
+        group.bench_function(BenchmarkId::new("indexed", count), |b| {
+            b.iter(|| {
+                for mut res in world.get_resources_trait_mut::<dyn BenchTrait>().flatten() {
+                    res.increment();
+                }
+            })
+        });
+
+        let (mut world, ids) = world_with_resources(count);
+        register_all_linear(&mut world, &ids);
+        group.bench_function(BenchmarkId::new("linear", count), |b| {
+            b.iter(|| {
+                for mut res in linear::get_resources_trait_mut::<dyn BenchTrait>(&mut world).flatten() {
+                    res.increment();
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, register, unregister, iterate_mut);
+criterion_main!(benches);
@@ -1,81 +1,447 @@
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{parse_quote, ItemTrait, Result};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit::Visit,
+    DeriveInput, FnArg, GenericParam, Generics, ItemTrait, Path, Result, ReturnType, Token, TraitItem, TraitItemFn, Type,
+    TypeParamBound, TypePath, WhereClause, WherePredicate,
+};
 
 /// When added to a trait declaration, generates the impls required to find a resource that implements a specific trait.
+///
+/// Impls are generated for both `dyn Trait` and `dyn Trait + Send + Sync`.
+/// Associated types are kept generic, so every `dyn Trait<Output = T>` can be used.
+///
+/// Supertraits that are also trait resources can be listed with `#[trait_resource(supertraits(Named))]`.
+/// Resources registered as the trait are then also registered (and unregistered) as those supertraits.
 #[proc_macro_attribute]
 pub fn trait_resource(attr: TokenStream, item: TokenStream) -> TokenStream {
-    impl_trait_resource(attr, item)
+    expand_trait_resource(attr, item)
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
-fn impl_trait_resource(arg: TokenStream, item: TokenStream) -> Result<TokenStream2> {
-    let _ = arg;
+/// Generates the impls required to find a resource that implements an existing trait,
+/// for traits that can not be marked with `#[trait_resource]`.
+///
+/// ```ignore
+/// impl_trait_resource!(dyn path::to::Trait);
+/// impl_trait_resource!(<T: 'static> dyn path::to::GenericTrait<T>);
+/// impl_trait_resource!(<T> dyn path::to::GenericTrait<T> where T: 'static);
+/// ```
+///
+/// The orphan rules only allow this for traits defined in the calling crate.
+/// Traits from other crates can be covered through a local wrapper trait with a blanket impl.
+#[proc_macro]
+pub fn impl_trait_resource(input: TokenStream) -> TokenStream {
+    expand_existing_trait(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `TraitResources`, registering the resource as every trait listed in `#[trait_resources(...)]`.
+///
+/// ```ignore
+/// #[derive(Resource, Default, TraitResources)]
+/// #[trait_resources(dyn IncrementTrait, dyn IncrementStringTrait)]
+/// struct NumberValueResource {
+///     value: i32,
+/// }
+/// ```
+#[proc_macro_derive(TraitResources, attributes(trait_resources))]
+pub fn derive_trait_resources(input: TokenStream) -> TokenStream {
+    expand_trait_resources(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The path to `bevy-trait-resource` from the calling crate.
+fn crate_path() -> TokenStream2 {
+    let my_crate = proc_macro_crate::crate_name("bevy-trait-resource").unwrap();
+    match my_crate {
+        proc_macro_crate::FoundCrate::Itself => quote! { crate },
+        proc_macro_crate::FoundCrate::Name(x) => {
+            let ident = format_ident!("{x}");
+            quote! { #ident }
+        }
+    }
+}
+
+fn expand_trait_resources(input: TokenStream) -> Result<TokenStream2> {
+    let input = syn::parse::<DeriveInput>(input)?;
+
+    let mut trait_objects = vec![];
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("trait_resources")) {
+        trait_objects.extend(attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?);
+    }
+
+    let my_crate = crate_path();
+    let imports = quote! { #my_crate::imports };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #my_crate::TraitResources for #name #ty_generics #where_clause {
+            fn register_all_traits(world: &mut #imports::World) {
+                #( <#imports::World as #my_crate::TraitResourceExt>::register_resource_as::<#trait_objects, Self>(world); )*
+            }
+        }
+    })
+}
+
+/// Finds the supertrait bounds named in `supertraits(...)` on the trait definition.
+fn parse_supertraits(arg: TokenStream, trait_definition: &ItemTrait) -> Result<Vec<Path>> {
+    let mut supertraits = vec![];
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("supertraits") {
+            meta.parse_nested_meta(|nested| {
+                let name = nested.path.require_ident()?;
+                let bound = trait_definition.supertraits.iter().find_map(|bound| match bound {
+                    TypeParamBound::Trait(bound) if bound.path.segments.last().is_some_and(|segment| segment.ident == *name) => {
+                        Some(bound.path.clone())
+                    }
+                    _ => None,
+                });
+
+                match bound {
+                    Some(path) => {
+                        supertraits.push(path);
+                        Ok(())
+                    }
+                    None => Err(nested.error(format!("`{name}` is not a supertrait of `{}`", trait_definition.ident))),
+                }
+            })
+        } else {
+            Err(meta.error("unsupported trait_resource property"))
+        }
+    });
+
+    syn::parse::Parser::parse(parser, arg)?;
+    Ok(supertraits)
+}
+
+/// Finds bare `Self` types, `Self::Item` paths are allowed in trait objects.
+#[derive(Default)]
+struct SelfTypeVisitor {
+    found: Option<Span>,
+}
+
+impl<'ast> Visit<'ast> for SelfTypeVisitor {
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        if self.found.is_none() && type_path.qself.is_none() && type_path.path.is_ident("Self") {
+            self.found = Some(type_path.span());
+        }
+        syn::visit::visit_type_path(self, type_path);
+    }
+}
+
+/// Returns the span of the first bare `Self` type.
+fn find_self_type(ty: &Type) -> Option<Span> {
+    let mut visitor = SelfTypeVisitor::default();
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+fn is_sized_bound(bound: &TypeParamBound) -> bool {
+    matches!(bound, TypeParamBound::Trait(bound) if bound.path.segments.last().is_some_and(|segment| segment.ident == "Sized"))
+}
+
+/// Whether the method is excluded from the trait object with `where Self: Sized`.
+fn requires_sized_self(method: &TraitItemFn) -> bool {
+    method.sig.generics.where_clause.as_ref().is_some_and(|where_clause| {
+        where_clause.predicates.iter().any(|predicate| match predicate {
+            WherePredicate::Type(predicate) => {
+                matches!(&predicate.bounded_ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self"))
+                    && predicate.bounds.iter().any(is_sized_bound)
+            }
+            _ => false,
+        })
+    })
+}
+
+fn method_error(method: &TraitItemFn, span: impl Spanned, reason: &str) -> syn::Error {
+    syn::Error::new(
+        span.span(),
+        format!(
+            "trait resources must be object safe, but method `{}` {reason}\n\
+             help: add `where Self: Sized` to the method to exclude it from the trait object",
+            method.sig.ident
+        ),
+    )
+}
+
+/// Checks that `dyn Trait` can be created and that it can be `'static`.
+fn validate_trait(trait_definition: &ItemTrait) -> Result<()> {
+    let mut errors = vec![];
+
+    for param in &trait_definition.generics.params {
+        if let GenericParam::Lifetime(param) = param {
+            errors.push(syn::Error::new(
+                param.span(),
+                format!(
+                    "trait resources must be `'static`, so `{}` can not have lifetime parameters",
+                    trait_definition.ident
+                ),
+            ));
+        }
+    }
+
+    for bound in &trait_definition.supertraits {
+        if is_sized_bound(bound) {
+            errors.push(syn::Error::new(
+                bound.span(),
+                "trait resources must be object safe, so they can not require `Self: Sized`",
+            ));
+        }
+    }
+
+    for item in &trait_definition.items {
+        match item {
+            TraitItem::Const(item) => errors.push(syn::Error::new(
+                item.ident.span(),
+                "trait resources must be object safe, so they can not have associated constants",
+            )),
+            TraitItem::Type(item) if !item.generics.params.is_empty() => errors.push(syn::Error::new(
+                item.generics.span(),
+                "trait resources must be object safe, so associated types can not have generic parameters",
+            )),
+            TraitItem::Fn(method) if !requires_sized_self(method) => {
+                let sig = &method.sig;
+
+                if !matches!(sig.inputs.first(), Some(FnArg::Receiver(_))) {
+                    errors.push(method_error(method, &sig.ident, "has no `self` receiver"));
+                }
+
+                if let Some(asyncness) = &sig.asyncness {
+                    errors.push(method_error(method, asyncness, "is `async`"));
+                }
+
+                if let Some(param) = sig
+                    .generics
+                    .params
+                    .iter()
+                    .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+                {
+                    errors.push(method_error(method, param, "has generic type parameters"));
+                }
+
+                for input in &sig.inputs {
+                    if let FnArg::Typed(input) = input {
+                        if let Some(span) = find_self_type(&input.ty) {
+                            errors.push(method_error(method, span, "uses `Self` as a parameter type"));
+                        }
+                    }
+                }
+
+                if let ReturnType::Type(_, ty) = &sig.output {
+                    if matches!(**ty, Type::ImplTrait(_)) {
+                        errors.push(method_error(method, ty, "returns `impl Trait`"));
+                    } else if let Some(span) = find_self_type(ty) {
+                        errors.push(method_error(method, span, "returns `Self`"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match errors.into_iter().reduce(|mut combined, error| {
+        combined.combine(error);
+        combined
+    }) {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+fn expand_trait_resource(arg: TokenStream, item: TokenStream) -> Result<TokenStream2> {
     let trait_definition = syn::parse::<ItemTrait>(item)?;
     let trait_name = trait_definition.ident.clone();
+    if let Err(error) = validate_trait(&trait_definition) {
+        // Keep the trait so the errors are not buried under unresolved names.
+        let error = error.into_compile_error();
+        return Ok(quote! {
+            #trait_definition
+            #error
+        });
+    }
+    let supertraits = parse_supertraits(arg, &trait_definition)?;
 
     let mut impl_generics_list = vec![];
     let mut trait_generics_list = vec![];
     let where_clause = trait_definition.generics.where_clause.clone();
 
     for param in &trait_definition.generics.params {
-        impl_generics_list.push(param.clone());
         match param {
             syn::GenericParam::Type(param) => {
+                // `TraitResource` is `'static`, so the trait object can only be made of `'static` types.
+                let mut param = param.clone();
+                param.bounds.push(parse_quote!('static));
+                param.eq_token = None;
+                param.default = None;
+                impl_generics_list.push(GenericParam::Type(param.clone()));
+
                 let ident = &param.ident;
                 trait_generics_list.push(quote! { #ident });
             }
             syn::GenericParam::Lifetime(param) => {
+                impl_generics_list.push(GenericParam::Lifetime(param.clone()));
                 let ident = &param.lifetime;
                 trait_generics_list.push(quote! { #ident });
             }
             syn::GenericParam::Const(param) => {
+                let mut param = param.clone();
+                param.eq_token = None;
+                param.default = None;
+                impl_generics_list.push(GenericParam::Const(param.clone()));
+
                 let ident = &param.ident;
                 trait_generics_list.push(quote! { #ident });
             }
         }
     }
 
-    let impl_generics = quote! { <#( #impl_generics_list ,)*> };
-    let trait_generics = quote! { <#( #trait_generics_list ,)*> };
+    // Associated types become generic parameters, so `dyn Trait<Output = T>` is covered for every `T`.
+    for item in &trait_definition.items {
+        if let TraitItem::Type(item) = item {
+            let ident = &item.ident;
+            let param_ident = format_ident!("__{}", ident);
+            let bounds = item.bounds.iter();
+            impl_generics_list.push(parse_quote!(#param_ident: 'static #( + #bounds )*));
+            trait_generics_list.push(quote! { #ident = #param_ident });
+        }
+    }
 
-    let trait_object = quote! { dyn #trait_name #trait_generics };
+    let marker_impl_code = trait_resource_impls(TraitResourceImpls {
+        impl_generics: impl_generics_list,
+        trait_path: quote! { #trait_name <#( #trait_generics_list ,)*> },
+        where_clause,
+        supertraits,
+    });
 
-    let my_crate = proc_macro_crate::crate_name("bevy-trait-resource").unwrap();
-    let my_crate = match my_crate {
-        proc_macro_crate::FoundCrate::Itself => quote! { crate },
-        proc_macro_crate::FoundCrate::Name(x) => {
-            let ident = quote::format_ident!("{x}");
-            quote! { #ident }
-        }
-    };
+    Ok(quote! {
+        #trait_definition
+        #marker_impl_code
+    })
+}
+
+/// The input of [`impl_trait_resource!`]: `<generics> dyn path::to::Trait<..> where ..`.
+struct ExistingTrait {
+    generics: Generics,
+    trait_path: Path,
+}
+
+impl Parse for ExistingTrait {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut generics = if input.peek(Token![<]) {
+            input.parse::<Generics>()?
+        } else {
+            Generics::default()
+        };
+        input.parse::<Token![dyn]>()?;
+        let trait_path = input.parse::<Path>()?;
+        generics.where_clause = input.parse::<Option<WhereClause>>()?;
+        Ok(Self { generics, trait_path })
+    }
+}
+
+fn expand_existing_trait(input: TokenStream) -> Result<TokenStream2> {
+    let ExistingTrait { generics, trait_path } = syn::parse::<ExistingTrait>(input)?;
+
+    Ok(trait_resource_impls(TraitResourceImpls {
+        impl_generics: generics.params.into_iter().collect(),
+        trait_path: quote! { #trait_path },
+        where_clause: generics.where_clause,
+        supertraits: vec![],
+    }))
+}
+
+/// Everything needed to implement `TraitResource` for the trait objects of a trait.
+struct TraitResourceImpls {
+    impl_generics: Vec<GenericParam>,
+    /// The trait with all generics and associated types, without `dyn`.
+    trait_path: TokenStream2,
+    where_clause: Option<WhereClause>,
+    supertraits: Vec<Path>,
+}
+
+fn trait_resource_impls(impls: TraitResourceImpls) -> TokenStream2 {
+    let TraitResourceImpls {
+        impl_generics: impl_generics_list,
+        trait_path,
+        where_clause,
+        supertraits,
+    } = impls;
+
+    let impl_generics = quote! { <#( #impl_generics_list ,)*> };
+
+    let my_crate = crate_path();
 
     let imports = quote! { #my_crate::imports };
 
     let trait_resource = quote! { #my_crate::TraitResource };
 
     let mut marker_impl_generics_list = impl_generics_list.clone();
-    marker_impl_generics_list.push(parse_quote!(__Resource: #trait_name #trait_generics + #imports::Resource));
+    marker_impl_generics_list.push(parse_quote!(__Resource: #trait_path + #imports::Resource));
 
     let marker_impl_generics = quote! { <#( #marker_impl_generics_list ,)*> };
 
-    let marker_impl_code = quote! {
-        impl #impl_generics #trait_resource for #trait_object #where_clause {}
+    let send_sync = quote! { + ::core::marker::Send + ::core::marker::Sync };
+
+    // Every `Resource` is `Send + Sync`, so the same marker bounds cover both trait objects.
+    let trait_objects = [
+        (quote! { dyn #trait_path }, quote! {}),
+        (quote! { (dyn #trait_path #send_sync) }, send_sync),
+    ];
 
-        impl #marker_impl_generics #my_crate::TraitResourceMarker::<#trait_object> for (__Resource,)
+    let marker_impl_code = trait_objects.iter().map(|(trait_object, auto_traits)| {
+        let supertrait_objects = supertraits
+            .iter()
+            .map(|path| quote! { (dyn #path #auto_traits) })
+            .collect::<Vec<_>>();
+
+        quote! {
+            impl #impl_generics #trait_resource for #trait_object #where_clause {}
+
+            impl #marker_impl_generics #my_crate::TraitResourceMarker::<#trait_object> for (__Resource,)
+            #where_clause
+            {
+                type Covered = __Resource;
+                fn cast(ptr: *mut u8) -> *mut #trait_object {
+                    ptr as *mut __Resource as *mut _
+                }
+                fn register_supertraits(world: &mut #imports::World) {
+                    #( #my_crate::register_implied_resource_as::<#supertrait_objects, __Resource>(world); )*
+                }
+                fn unregister_supertraits(world: &mut #imports::World) {
+                    #( #my_crate::unregister_implied_resource_from::<#supertrait_objects, __Resource>(world); )*
+                }
+            }
+        }
+    });
+
+    // Non-send resources are not `Send + Sync`, so they are only covered by the plain trait object.
+    let mut non_send_impl_generics_list = impl_generics_list.clone();
+    non_send_impl_generics_list.push(parse_quote!(__Resource: #trait_path + 'static));
+
+    let non_send_impl_generics = quote! { <#( #non_send_impl_generics_list ,)*> };
+
+    quote! {
+        #( #marker_impl_code )*
+
+        impl #non_send_impl_generics #my_crate::TraitNonSendMarker::<dyn #trait_path> for (__Resource,)
         #where_clause
         {
             type Covered = __Resource;
-            fn cast(ptr: *mut u8) -> *mut #trait_object {
+            fn cast(ptr: *mut u8) -> *mut dyn #trait_path {
                 ptr as *mut __Resource as *mut _
             }
         }
-    };
-
-    Ok(quote! {
-        #trait_definition
-        #marker_impl_code
-    })
+    }
 }
@@ -0,0 +1,104 @@
+use std::any::{type_name, TypeId};
+
+use bevy::prelude::*;
+
+use crate::{TraitResource, TraitResourceMarker};
+
+/// Identifies an entry of a [`TraitCollection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TraitCollectionId(u64);
+
+/// Stores any number of values as `Trait`, also several of the same type.
+/// Its entries are returned after the registered resources by
+/// [`TraitResourceExt::get_resources_trait`](crate::TraitResourceExt::get_resources_trait),
+/// [`TraitResourceExt::get_resources_trait_mut`](crate::TraitResourceExt::get_resources_trait_mut)
+/// and [`TraitResourceExt::get_resources_trait_pair_mut`](crate::TraitResourceExt::get_resources_trait_pair_mut),
+/// so events sent with [`TraitResourceAppExt::add_trait_event_dispatch`](crate::TraitResourceAppExt::add_trait_event_dispatch) reach them too.
+///
+/// Entries are not resources and are not registered, so every other API only sees the registered resources:
+/// intersections, [`TraitResourceExt::get_all_resources_trait`](crate::TraitResourceExt::get_all_resources_trait),
+/// scopes, handles, taking and restoring, run conditions and mirroring.
+#[derive(Resource)]
+pub struct TraitCollection<Trait: ?Sized> {
+    pub(crate) entries: Vec<CollectionEntry<Trait>>,
+    next_id: u64,
+}
+
+pub(crate) struct CollectionEntry<Trait: ?Sized> {
+    id: TraitCollectionId,
+    pub(crate) type_id: TypeId,
+    pub(crate) type_name: &'static str,
+    pub(crate) value: Box<Trait>,
+}
+
+// SAFETY: `Box<Trait>` is only `Send + Sync` if the trait object is, but the value behind it always is.
+// Entries are only created by `TraitCollection::push`, which requires the value to be a `Resource`,
+// and `Resource` requires `Send + Sync`. `value` is never replaced, only borrowed or moved out by `TraitCollection::remove`.
+unsafe impl<Trait: ?Sized> Send for CollectionEntry<Trait> {}
+unsafe impl<Trait: ?Sized> Sync for CollectionEntry<Trait> {}
+
+impl<Trait: ?Sized> Default for TraitCollection<Trait> {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            next_id: 0,
+        }
+    }
+}
+
+impl<Trait: ?Sized + TraitResource> TraitCollection<Trait> {
+    /// Adds a value to the end of the collection.
+    pub fn push<R: Resource>(&mut self, value: R) -> TraitCollectionId
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        let ptr = Box::into_raw(Box::new(value)).cast::<u8>();
+        // SAFETY: The cast of `R` only changes the type of the pointer to the boxed value.
+        let value = unsafe { Box::from_raw(<(R,)>::cast(ptr)) };
+
+        let id = TraitCollectionId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(CollectionEntry {
+            id,
+            type_id: TypeId::of::<R>(),
+            type_name: type_name::<R>(),
+            value,
+        });
+        id
+    }
+}
+
+impl<Trait: ?Sized> TraitCollection<Trait> {
+    /// Removes an entry, keeping the order of the others.
+    pub fn remove(&mut self, id: TraitCollectionId) -> Option<Box<Trait>> {
+        let index = self.entries.iter().position(|entry| entry.id == id)?;
+        Some(self.entries.remove(index).value)
+    }
+
+    pub fn get(&self, id: TraitCollectionId) -> Option<&Trait> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, id: TraitCollectionId) -> Option<&mut Trait> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.value.as_mut())
+    }
+
+    /// The ids of the entries, in order.
+    pub fn ids(&self) -> impl Iterator<Item = TraitCollectionId> + '_ {
+        self.entries.iter().map(|entry| entry.id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
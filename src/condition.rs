@@ -0,0 +1,49 @@
+use bevy::{ecs::system::SystemChangeTick, prelude::*};
+
+use crate::{TraitResource, TraitResourceRegistry};
+
+/// Run condition that is `true` if any resource is registered as `Trait` and active.
+pub fn any_trait_resource_exists<Trait: ?Sized + TraitResource>() -> impl Condition<()> {
+    trait_resource_count_at_least::<Trait>(1)
+}
+
+/// Run condition that is `true` if at least `count` resources are registered as `Trait` and active.
+/// Only the registry is read, so a resource that was removed without unregistering it is still counted.
+pub fn trait_resource_count_at_least<Trait: ?Sized + TraitResource>(count: usize) -> impl Condition<()> {
+    IntoSystem::into_system(move |registry: Option<Res<TraitResourceRegistry<Trait>>>| {
+        registry.map_or(0, |registry| registry.active_ids().count()) >= count
+    })
+}
+
+/// Run condition that is `true` if any active resource registered as `Trait` was added or mutably
+/// accessed since the condition last ran.
+///
+/// The registered resources are only known when the condition runs, so it reads the whole world
+/// and the system it gates does not run in parallel with systems that write to the world.
+pub fn any_trait_resource_changed<Trait: ?Sized + TraitResource>() -> impl Condition<()> {
+    IntoSystem::into_system(|world: &World, ticks: SystemChangeTick| {
+        let Some(registry) = world.get_resource::<TraitResourceRegistry<Trait>>() else {
+            return false;
+        };
+
+        registry.active_ids().any(|id| {
+            world
+                .storages()
+                .resources
+                .get(id)
+                .and_then(|data| data.get_ticks())
+                .is_some_and(|resource_ticks| resource_ticks.is_changed(ticks.last_run(), ticks.this_run()))
+        })
+    })
+}
+
+/// Run condition that is `true` if resources were registered as `Trait`, unregistered, enabled or disabled
+/// since the condition last ran.
+pub fn trait_registry_changed<Trait: ?Sized + TraitResource>() -> impl Condition<()> {
+    IntoSystem::into_system(|registry: Option<Res<TraitResourceRegistry<Trait>>>, mut existed: Local<bool>| {
+        // The registry is removed with its last registration.
+        let removed = *existed && registry.is_none();
+        *existed = registry.is_some();
+        removed || registry.is_some_and(|registry| registry.is_changed())
+    })
+}
@@ -0,0 +1,37 @@
+use bevy::{ecs::event::ManualEventReader, prelude::*};
+
+use crate::{trait_resource, TraitResourceExt};
+
+/// A resource that receives every event of type `E`.
+///
+/// Register listeners with `register_resource_as::<dyn EventListener<E>, R>()` and
+/// start the dispatch with [`TraitResourceAppExt::add_trait_event_dispatch`](crate::TraitResourceAppExt::add_trait_event_dispatch).
+#[trait_resource]
+pub trait EventListener<E: Event> {
+    fn on_event(&mut self, event: &E);
+}
+
+/// Marks that the dispatch system for `E` has been added.
+#[derive(Resource)]
+pub(crate) struct TraitEventDispatch<E: Event>(std::marker::PhantomData<E>);
+
+impl<E: Event> Default for TraitEventDispatch<E> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+/// Sends every new event of type `E` to the listeners in registry order.
+pub(crate) fn dispatch_trait_events<E: Event>(world: &mut World, mut reader: Local<ManualEventReader<E>>) {
+    if !world.contains_resource::<Events<E>>() {
+        return;
+    }
+
+    world.resource_scope(|world, events: Mut<Events<E>>| {
+        for event in reader.read(&events) {
+            for mut listener in world.get_resources_trait_mut::<dyn EventListener<E>>().flatten() {
+                listener.on_event(event);
+            }
+        }
+    });
+}
@@ -0,0 +1,365 @@
+use std::{
+    any::TypeId,
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use bevy::{
+    ecs::{
+        component::{ComponentId, ComponentInfo},
+        world::unsafe_world_cell::UnsafeWorldCell,
+    },
+    ptr::{Ptr, PtrMut},
+};
+
+use crate::{DynCtor, TraitResource, TraitResourceHandle, TraitResourceRegistry};
+
+/// Which resource an item of the iterators comes from.
+#[derive(Clone, Copy)]
+struct Identity<'w> {
+    component_id: ComponentId,
+    /// `None` for resources without a Rust type.
+    type_id: Option<TypeId>,
+    type_name: &'w str,
+    world: UnsafeWorldCell<'w>,
+    /// Non-send resources are not in the registries of other traits.
+    non_send: bool,
+}
+
+impl<'w> Identity<'w> {
+    fn from_info(info: &'w ComponentInfo, world: UnsafeWorldCell<'w>, non_send: bool) -> Self {
+        Self {
+            component_id: info.id(),
+            type_id: info.type_id(),
+            type_name: info.name(),
+            world,
+            non_send,
+        }
+    }
+
+    /// The handle of a resource registered as `Trait`, `None` for non-send resources.
+    fn handle<Trait: ?Sized + TraitResource>(&self) -> Option<TraitResourceHandle<Trait>> {
+        if self.non_send {
+            return None;
+        }
+
+        // SAFETY: The iterators that create items only read the registry of their trait.
+        let registry = unsafe { self.world.get_resource::<TraitResourceRegistry<Trait>>() }?;
+        let data = registry.get(self.component_id)?;
+        Some(TraitResourceHandle::new(self.world.id(), data))
+    }
+}
+
+/// A resource as `Trait`, returned by [`TraitResourceIterator`](crate::TraitResourceIterator)
+/// and the other shared iterators. Dereferences to `Trait`.
+pub struct TraitResourceRef<'w, Trait: ?Sized> {
+    value: &'w Trait,
+    /// The concrete value, which is not where `value` points for projections.
+    /// `None` for entries of a [`TraitCollection`](crate::TraitCollection), which are not resources of their own.
+    resource: Option<Ptr<'w>>,
+    identity: Identity<'w>,
+}
+
+impl<'w, Trait: ?Sized> TraitResourceRef<'w, Trait> {
+    /// # Safety
+    /// `resource` must point to the resource of `info`, which `value` was cast from.
+    pub(crate) unsafe fn from_resource(value: &'w Trait, resource: Ptr<'w>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self {
+        Self {
+            value,
+            resource: Some(resource),
+            identity: Identity::from_info(info, world, false),
+        }
+    }
+
+    /// # Safety
+    /// `resource` must point to the non-send resource of `info`, which `value` was cast from.
+    pub(crate) unsafe fn from_non_send(value: &'w Trait, resource: Ptr<'w>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self {
+        Self {
+            value,
+            resource: Some(resource),
+            identity: Identity::from_info(info, world, true),
+        }
+    }
+
+    pub(crate) fn from_collection(
+        value: &'w Trait,
+        component_id: ComponentId,
+        type_id: TypeId,
+        type_name: &'w str,
+        world: UnsafeWorldCell<'w>,
+    ) -> Self {
+        Self {
+            value,
+            resource: None,
+            identity: Identity { component_id, type_id: Some(type_id), type_name, world, non_send: false },
+        }
+    }
+
+    /// The component id of the resource, or of the [`TraitCollection`](crate::TraitCollection) for its entries.
+    pub fn component_id(&self) -> ComponentId {
+        self.identity.component_id
+    }
+
+    /// The type id of the concrete type.
+    /// # Panics
+    /// Panics for resources without a Rust type, registered with
+    /// [`TraitResourceExt::register_resource_by_id_as`](crate::TraitResourceExt::register_resource_by_id_as).
+    /// Use [`Self::try_type_id`] for those.
+    pub fn type_id(&self) -> TypeId {
+        self.identity.type_id.expect("The resource has no Rust type")
+    }
+
+    /// The type id of the concrete type, `None` for resources registered by component id without a Rust type.
+    pub fn try_type_id(&self) -> Option<TypeId> {
+        self.identity.type_id
+    }
+
+    /// The type name of the concrete type.
+    pub fn type_name(&self) -> &'w str {
+        self.identity.type_name
+    }
+
+    pub fn downcast_ref<R: 'static>(&self) -> Option<&'w R> {
+        if self.identity.type_id != Some(TypeId::of::<R>()) {
+            return None;
+        }
+
+        // SAFETY: The resource, or the collection entry behind `value`, is of type `R`.
+        unsafe {
+            match self.resource {
+                Some(resource) => Some(resource.deref::<R>()),
+                None => Some(NonNull::from(self.value).cast::<R>().as_ref()),
+            }
+        }
+    }
+
+    pub fn into_inner(self) -> &'w Trait {
+        self.value
+    }
+}
+
+impl<Trait: ?Sized + TraitResource> TraitResourceRef<'_, Trait> {
+    /// A handle to the resource, `None` for entries of a [`TraitCollection`](crate::TraitCollection)
+    /// and non-send resources.
+    pub fn handle(&self) -> Option<TraitResourceHandle<Trait>> {
+        self.resource?;
+        self.identity.handle()
+    }
+}
+
+impl<Trait: ?Sized> Clone for TraitResourceRef<'_, Trait> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Trait: ?Sized> Copy for TraitResourceRef<'_, Trait> {}
+
+// SAFETY: The item only gives shared access to the resource, as `Trait` or as the concrete type that implements it,
+// so it can be shared like `&Trait`. Every type behind a `Sync` trait object is `Sync`, including non-send resources.
+unsafe impl<Trait: ?Sized + Sync> Send for TraitResourceRef<'_, Trait> {}
+unsafe impl<Trait: ?Sized + Sync> Sync for TraitResourceRef<'_, Trait> {}
+
+impl<Trait: ?Sized> Deref for TraitResourceRef<'_, Trait> {
+    type Target = Trait;
+
+    fn deref(&self) -> &Trait {
+        self.value
+    }
+}
+
+impl<Trait: ?Sized + fmt::Debug> fmt::Debug for TraitResourceRef<'_, Trait> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+enum MutTarget<'w, Trait: ?Sized> {
+    /// A registered resource, cast again on every access so references to it never alias.
+    Resource(PtrMut<'w>, DynCtor<Trait>),
+    /// An entry of a [`TraitCollection`](crate::TraitCollection).
+    Entry(&'w mut Trait),
+}
+
+/// A resource as mutable `Trait`, returned by [`TraitResourceIteratorMut`](crate::TraitResourceIteratorMut)
+/// and the other mutable iterators. Dereferences to `Trait`.
+pub struct TraitResourceMut<'w, Trait: ?Sized> {
+    target: MutTarget<'w, Trait>,
+    identity: Identity<'w>,
+}
+
+impl<'w, Trait: ?Sized> TraitResourceMut<'w, Trait> {
+    /// # Safety
+    /// `resource` must point to the resource of `info` and `trait_ptr` must be registered for it.
+    pub(crate) unsafe fn from_resource(resource: PtrMut<'w>, trait_ptr: DynCtor<Trait>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self {
+        Self {
+            target: MutTarget::Resource(resource, trait_ptr),
+            identity: Identity::from_info(info, world, false),
+        }
+    }
+
+    /// # Safety
+    /// `resource` must point to the non-send resource of `info` and `trait_ptr` must be registered for it.
+    pub(crate) unsafe fn from_non_send(resource: PtrMut<'w>, trait_ptr: DynCtor<Trait>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self {
+        Self {
+            target: MutTarget::Resource(resource, trait_ptr),
+            identity: Identity::from_info(info, world, true),
+        }
+    }
+
+    pub(crate) fn from_collection(
+        value: &'w mut Trait,
+        component_id: ComponentId,
+        type_id: TypeId,
+        type_name: &'w str,
+        world: UnsafeWorldCell<'w>,
+    ) -> Self {
+        Self {
+            target: MutTarget::Entry(value),
+            identity: Identity { component_id, type_id: Some(type_id), type_name, world, non_send: false },
+        }
+    }
+
+    /// The component id of the resource, or of the [`TraitCollection`](crate::TraitCollection) for its entries.
+    pub fn component_id(&self) -> ComponentId {
+        self.identity.component_id
+    }
+
+    /// The type id of the concrete type.
+    /// # Panics
+    /// Panics for resources without a Rust type, registered with
+    /// [`TraitResourceExt::register_resource_by_id_as`](crate::TraitResourceExt::register_resource_by_id_as).
+    /// Use [`Self::try_type_id`] for those.
+    pub fn type_id(&self) -> TypeId {
+        self.identity.type_id.expect("The resource has no Rust type")
+    }
+
+    /// The type id of the concrete type, `None` for resources registered by component id without a Rust type.
+    pub fn try_type_id(&self) -> Option<TypeId> {
+        self.identity.type_id
+    }
+
+    /// The type name of the concrete type.
+    pub fn type_name(&self) -> &'w str {
+        self.identity.type_name
+    }
+
+    pub fn downcast_ref<R: 'static>(&self) -> Option<&R> {
+        if self.identity.type_id != Some(TypeId::of::<R>()) {
+            return None;
+        }
+
+        // SAFETY: The resource or the collection entry is of type `R`.
+        unsafe {
+            match &self.target {
+                MutTarget::Resource(resource, _) => Some(resource.as_ref().deref::<R>()),
+                MutTarget::Entry(value) => Some(NonNull::from(&**value).cast::<R>().as_ref()),
+            }
+        }
+    }
+
+    pub fn downcast_mut<R: 'static>(&mut self) -> Option<&mut R> {
+        if self.identity.type_id != Some(TypeId::of::<R>()) {
+            return None;
+        }
+
+        // SAFETY: The resource or the collection entry is of type `R`.
+        unsafe {
+            match &mut self.target {
+                MutTarget::Resource(resource, _) => Some(resource.reborrow().deref_mut::<R>()),
+                MutTarget::Entry(value) => Some(NonNull::from(&mut **value).cast::<R>().as_mut()),
+            }
+        }
+    }
+
+    pub fn into_inner(self) -> &'w mut Trait {
+        match self.target {
+            // SAFETY: The cast is registered for the resource.
+            MutTarget::Resource(resource, trait_ptr) => unsafe { trait_ptr.cast_mut(resource) },
+            MutTarget::Entry(value) => value,
+        }
+    }
+}
+
+impl<Trait: ?Sized + TraitResource> TraitResourceMut<'_, Trait> {
+    /// A handle to the resource, `None` for entries of a [`TraitCollection`](crate::TraitCollection)
+    /// and non-send resources.
+    pub fn handle(&self) -> Option<TraitResourceHandle<Trait>> {
+        match self.target {
+            MutTarget::Resource(..) => self.identity.handle(),
+            MutTarget::Entry(_) => None,
+        }
+    }
+}
+
+// SAFETY: The item gives exclusive access to the resource, as `Trait` or as the concrete type that implements it,
+// so it can be moved like `&mut Trait`. Every type behind a `Send` trait object is `Send`, including non-send resources.
+unsafe impl<Trait: ?Sized + Send> Send for TraitResourceMut<'_, Trait> {}
+
+impl<Trait: ?Sized> Deref for TraitResourceMut<'_, Trait> {
+    type Target = Trait;
+
+    fn deref(&self) -> &Trait {
+        match &self.target {
+            // SAFETY: The cast is registered for the resource.
+            MutTarget::Resource(resource, trait_ptr) => unsafe { trait_ptr.cast(resource.as_ref()) },
+            MutTarget::Entry(value) => value,
+        }
+    }
+}
+
+impl<Trait: ?Sized> DerefMut for TraitResourceMut<'_, Trait> {
+    fn deref_mut(&mut self) -> &mut Trait {
+        match &mut self.target {
+            // SAFETY: The cast is registered for the resource.
+            MutTarget::Resource(resource, trait_ptr) => unsafe { trait_ptr.cast_mut(resource.reborrow()) },
+            MutTarget::Entry(value) => value,
+        }
+    }
+}
+
+impl<Trait: ?Sized + fmt::Debug> fmt::Debug for TraitResourceMut<'_, Trait> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Get the resource of `item` as `B`, if it is also registered as `B`.
+/// Entries of a [`TraitCollection`](crate::TraitCollection) and non-send resources are never cross-cast.
+pub fn cross_cast<'w, A: ?Sized + TraitResource, B: ?Sized + TraitResource>(item: &TraitResourceRef<'w, A>) -> Option<&'w B> {
+    let resource = item.resource?;
+    if item.identity.non_send {
+        return None;
+    }
+
+    // SAFETY: Shared items are created from a shared borrow of the world.
+    let world = unsafe { item.identity.world.world() };
+    let data = world.get_resource::<TraitResourceRegistry<B>>()?.get(item.component_id())?;
+    // SAFETY: The cast was registered for the resource behind the component id.
+    unsafe { Some(data.trait_ptr.cast(resource)) }
+}
+
+/// Same as [`cross_cast`], but mutable. The item can not be used while the result is borrowed.
+pub fn cross_cast_mut<'a, A: ?Sized + TraitResource, B: ?Sized + TraitResource>(item: &'a mut TraitResourceMut<'_, A>) -> Option<&'a mut B> {
+    let MutTarget::Resource(resource, _) = &mut item.target else {
+        return None;
+    };
+    if item.identity.non_send {
+        return None;
+    }
+
+    let world = item.identity.world;
+    let registry_b_id = world.components().resource_id::<TraitResourceRegistry<B>>()?;
+
+    // SAFETY: The iterator that created the item only reads the registry of `A`. The registry of `B`
+    // is only read if it is not one of the resources the iterator returns mutably.
+    let registry_a = unsafe { world.get_resource::<TraitResourceRegistry<A>>() }?;
+    if registry_a.get(registry_b_id).is_some() {
+        return None;
+    }
+    let data = unsafe { world.get_resource::<TraitResourceRegistry<B>>() }?.get(item.identity.component_id)?;
+
+    // SAFETY: The cast was registered for the resource behind the component id.
+    unsafe { Some(data.trait_ptr.cast_mut(resource.reborrow())) }
+}
@@ -29,7 +29,7 @@
 //! 
 //! pub fn increment_value_system(world: &mut World) {
 //!     for res_opt in world.get_resources_trait_mut::<dyn IncrementTrait>() {
-//!         if let Some(res) = res_opt {
+//!         if let Some(mut res) = res_opt {
 //!             res.increment();
 //!         }
 //!     }
@@ -89,7 +89,7 @@
 //!# 
 //!# pub fn increment_value_system(world: &mut World) {
 //!#     for res_opt in world.get_resources_trait_mut::<dyn IncrementTrait>() {
-//!#         if let Some(res) = res_opt {
+//!#         if let Some(mut res) = res_opt {
 //!#             res.increment();
 //!#         }
 //!#     }
@@ -152,18 +152,146 @@
 //!#        .run();
 //!# }
 //! ```
+//! Trait objects that are `Send + Sync`.
+//! 
+//! Every resource is `Send + Sync`, so a resource can also be registered as `dyn Trait + Send + Sync`.
+//! The items of those iterators can be moved to other threads.
+//! Note that `dyn Trait` and `dyn Trait + Send + Sync` are separate registries.
+//! ```rust
+//!# use bevy_trait_resource::*;
+//!# use bevy::prelude::*;
+//!# use bevy_trait_resource::{trait_resource, TraitResourceExt};
+//!# 
+//!# #[trait_resource]
+//!# pub trait IncrementTrait {
+//!#     fn value(&self) -> i32;
+//!#     fn increment(&mut self);
+//!# }
+//!# 
+//!# #[derive(Resource, Default)]
+//!# struct NumberValueResource {
+//!#     value: i32,
+//!# }
+//!# 
+//!# impl IncrementTrait for NumberValueResource {
+//!#     fn value(&self) -> i32 {
+//!#         self.value
+//!#     }
+//!#     fn increment(&mut self) {
+//!#         self.value += 1;
+//!#     }
+//!# }
+//!# 
+//!# fn main() {
+//!# let mut world = World::new();
+//! world.init_resource_as::<dyn IncrementTrait + Send + Sync, NumberValueResource>();
+//! 
+//! std::thread::scope(|scope| {
+//!     for res in world.get_resources_trait::<dyn IncrementTrait + Send + Sync>().flatten() {
+//!         scope.spawn(move || res.value());
+//!     }
+//! });
+//!# }
+//! ```
+//! Supertraits that are trait resources can be listed in the attribute.
+//! Resources registered as the trait are then also registered (and unregistered) as those supertraits.
+//! Registering a resource as a supertrait directly keeps it there when it is unregistered from the subtrait.
+//! ```rust
+//!# use bevy_trait_resource::*;
+//!# use bevy::prelude::*;
+//!# use bevy_trait_resource::{trait_resource, TraitResourceExt};
+//!# 
+//! #[trait_resource]
+//! pub trait Named {
+//!     fn name(&self) -> String;
+//! }
+//! 
+//! #[trait_resource(supertraits(Named))]
+//! pub trait Animal: Named {
+//!     fn legs(&self) -> u32;
+//! }
+//!# 
+//!# #[derive(Resource, Default)]
+//!# struct DogResource;
+//!# 
+//!# impl Named for DogResource {
+//!#     fn name(&self) -> String {
+//!#         "Dog".to_owned()
+//!#     }
+//!# }
+//!# 
+//!# impl Animal for DogResource {
+//!#     fn legs(&self) -> u32 {
+//!#         4
+//!#     }
+//!# }
+//!# 
+//!# fn main() {
+//!# let mut world = World::new();
+//! world.init_resource_as::<dyn Animal, DogResource>();
+//! 
+//! assert_eq!(world.get_resources_trait::<dyn Named>().flatten().count(), 1);
+//!# }
+//! ```
+//! Trait resources must be object safe. Methods that can not be called on a trait object
+//! must opt out with `where Self: Sized`, otherwise the attribute reports an error on the method.
+//! ```compile_fail
+//!# use bevy_trait_resource::*;
+//! #[trait_resource]
+//! pub trait CloneTrait {
+//!     // error: method `duplicate` returns `Self`
+//!     fn duplicate(&self) -> Self;
+//! }
+//!# 
+//!# fn main() {}
+//! ```
+//! ```rust
+//!# use bevy_trait_resource::*;
+//! #[trait_resource]
+//! pub trait CloneTrait {
+//!     fn duplicate(&self) -> Self where Self: Sized;
+//! }
+//!# 
+//!# fn main() {}
+//! ```
 
-use std::marker::PhantomData;
+use std::{any::TypeId, error::Error, fmt, ptr::NonNull};
 use bevy::{
-    ecs::component::ComponentId,
+    app::AppLabel,
+    ecs::{
+        component::{ComponentId, ComponentInfo, Components},
+        schedule::{BoxedCondition, Condition},
+        world::{unsafe_world_cell::UnsafeWorldCell, WorldId},
+    },
+    utils::HashMap,
     prelude::*,
     ptr::{Ptr, PtrMut}
 };
 
+mod collection;
+mod condition;
+mod event;
+mod item;
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod linear;
+mod mirror;
+mod query;
 #[cfg(test)]
 mod tests;
 
-pub use bevy_trait_resource_macro::trait_resource;
+pub use collection::{TraitCollection, TraitCollectionId};
+pub use condition::{any_trait_resource_changed, any_trait_resource_exists, trait_registry_changed, trait_resource_count_at_least};
+pub use event::EventListener;
+pub use item::{cross_cast, cross_cast_mut, TraitResourceMut, TraitResourceRef};
+pub use mirror::{extract_mirrored_trait_resources, mirror_trait_resources};
+pub use query::TraitResourceTuple;
+
+pub use bevy_trait_resource_macro::{impl_trait_resource, trait_resource, TraitResources};
+
+// Downstream crates can not implement these because of the orphan rules.
+impl_trait_resource!(dyn std::fmt::Debug);
+impl_trait_resource!(dyn bevy::reflect::Reflect);
 
 #[doc(hidden)]
 pub mod imports {
@@ -175,16 +303,101 @@ pub mod imports {
 
 pub trait TraitResource: 'static {}
 
+/// A resource that knows every trait it should be registered as.
+/// Implement it with `#[derive(TraitResources)]` and `#[trait_resources(dyn A, dyn B)]`.
+pub trait TraitResources: Resource {
+    /// Registers the resource as every listed trait.
+    /// # Panics
+    /// Panics if the resource does not exist.
+    fn register_all_traits(world: &mut World);
+}
+
 #[doc(hidden)]
 pub trait TraitResourceMarker<Trait: ?Sized + TraitResource> {
     type Covered: Resource;
     fn cast(_: *mut u8) -> *mut Trait;
+    /// Registers the resource as the supertraits listed in `#[trait_resource(supertraits(...))]`.
+    fn register_supertraits(_world: &mut World) {}
+    /// Unregisters the resource from the supertraits listed in `#[trait_resource(supertraits(...))]`.
+    fn unregister_supertraits(_world: &mut World) {}
+}
+
+/// Registers a resource as a supertrait of a trait it was registered as.
+/// Used by [`TraitResourceMarker::register_supertraits`].
+#[doc(hidden)]
+pub fn register_implied_resource_as<Trait: ?Sized + TraitResource, R: Resource>(world: &mut World)
+where
+    (R,): TraitResourceMarker<Trait, Covered = R>,
+{
+    let resource_id = world
+        .components()
+        .resource_id::<R>()
+        .expect("Trying to register a nonexistent resource");
+
+    let resource_registry = world
+        .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+        .into_inner();
+
+    let trait_data = TraitData {
+        explicit: false,
+        implied_by: 1,
+        supertraits: true,
+        ..TraitData::new(resource_id, DynCtor::Cast(<(R,)>::cast), Some(ResourceMover::with_supertraits::<Trait, R>()))
+    };
+
+    if resource_registry.register_implied(trait_data) {
+        <(R,) as TraitResourceMarker<Trait>>::register_supertraits(world);
+    }
+}
+
+/// Undoes [`register_implied_resource_as`]. The resource stays registered while it was registered
+/// as the trait itself or as another subtrait.
+/// Used by [`TraitResourceMarker::unregister_supertraits`].
+#[doc(hidden)]
+pub fn unregister_implied_resource_from<Trait: ?Sized + TraitResource, R: Resource>(world: &mut World)
+where
+    (R,): TraitResourceMarker<Trait, Covered = R>,
+{
+    let Some(resource_id) = world.components().resource_id::<R>() else {
+        return;
+    };
+
+    let Some(mut registry) = world.get_resource_mut::<TraitResourceRegistry<Trait>>() else {
+        return;
+    };
+
+    let Some(data) = registry.get_mut(resource_id) else {
+        return;
+    };
+
+    data.implied_by = data.implied_by.saturating_sub(1);
+    if data.explicit || data.implied_by > 0 {
+        return;
+    }
+
+    let supertraits = data.supertraits;
+    if registry.unregister(resource_id) == 0 {
+        world.remove_resource::<TraitResourceRegistry<Trait>>();
+    }
+
+    if supertraits {
+        <(R,) as TraitResourceMarker<Trait>>::unregister_supertraits(world);
+    }
+}
+
+#[doc(hidden)]
+pub trait TraitNonSendMarker<Trait: ?Sized + TraitResource> {
+    type Covered: 'static;
+    fn cast(_: *mut u8) -> *mut Trait;
 }
 
 /// Turns an untyped pointer into a trait object pointer,
 /// for a specific erased concrete type.
-struct DynCtor<Trait: ?Sized> {
-    cast: unsafe fn(*mut u8) -> *mut Trait,
+enum DynCtor<Trait: ?Sized> {
+    /// The resource itself implements the trait.
+    Cast(unsafe fn(*mut u8) -> *mut Trait),
+    /// A part of the resource implements the trait.
+    Projection(Projection<Trait>),
 }
 
 impl<T: ?Sized> Copy for DynCtor<T> {}
@@ -196,18 +409,192 @@ impl<T: ?Sized> Clone for DynCtor<T> {
 
 impl<Trait: ?Sized> DynCtor<Trait> {
     #[inline]
-    unsafe fn cast(self, ptr: Ptr) -> &Trait {
-        &*(self.cast)(ptr.as_ptr())
+    unsafe fn cast(self, ptr: Ptr<'_>) -> &Trait {
+        match self {
+            Self::Cast(cast) => &*cast(ptr.as_ptr()),
+            Self::Projection(projection) => &*(projection.get)(projection.project, ptr.as_ptr()),
+        }
     }
     #[inline]
-    unsafe fn cast_mut(self, ptr: PtrMut) -> &mut Trait {
-        &mut *(self.cast)(ptr.as_ptr())
+    unsafe fn cast_mut(self, ptr: PtrMut<'_>) -> &mut Trait {
+        match self {
+            Self::Cast(cast) => &mut *cast(ptr.as_ptr()),
+            Self::Projection(projection) => &mut *(projection.get_mut)(projection.project_mut, ptr.as_ptr()),
+        }
+    }
+}
+
+/// Projects a resource to a part of it that implements a trait.
+/// The projection functions are stored as `fn()` so the type does not depend on the resource,
+/// and are only called through `get` and `get_mut`, which turn them back into their original types.
+/// Separate functions are needed for shared access, because a mutable reference can not be created from a shared resource.
+struct Projection<Trait: ?Sized> {
+    project: fn(),
+    project_mut: fn(),
+    get: unsafe fn(fn(), *mut u8) -> *const Trait,
+    get_mut: unsafe fn(fn(), *mut u8) -> *mut Trait,
+}
+
+impl<T: ?Sized> Copy for Projection<T> {}
+impl<T: ?Sized> Clone for Projection<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Trait: ?Sized> Projection<Trait> {
+    fn new<R: 'static>(project: fn(&R) -> &Trait, project_mut: fn(&mut R) -> &mut Trait) -> Self {
+        Self {
+            // SAFETY: Function pointers have the same size, and they are only called after turning them back.
+            project: unsafe { std::mem::transmute::<fn(&R) -> &Trait, fn()>(project) },
+            project_mut: unsafe { std::mem::transmute::<fn(&mut R) -> &mut Trait, fn()>(project_mut) },
+            get: Self::get::<R>,
+            get_mut: Self::get_mut::<R>,
+        }
+    }
+
+    /// # Safety
+    /// `project` must be the shared projection of `R` and `ptr` must point to an `R`.
+    unsafe fn get<R: 'static>(project: fn(), ptr: *mut u8) -> *const Trait {
+        let project = std::mem::transmute::<fn(), fn(&R) -> &Trait>(project);
+        project(&*ptr.cast::<R>())
+    }
+
+    /// # Safety
+    /// `project_mut` must be the mutable projection of `R` and `ptr` must point to an `R` that can be borrowed mutably.
+    unsafe fn get_mut<R: 'static>(project_mut: fn(), ptr: *mut u8) -> *mut Trait {
+        let project_mut = std::mem::transmute::<fn(), fn(&mut R) -> &mut Trait>(project_mut);
+        project_mut(&mut *ptr.cast::<R>())
+    }
+}
+
+/// Runs with the world and a resource that was removed from it.
+type ScopeFn<'a> = dyn FnMut(&mut World, PtrMut<'_>) + 'a;
+
+/// Moves a resource in and out of the world,
+/// for a specific erased concrete type.
+struct ResourceMover {
+    take: fn(&mut World) -> Option<NonNull<u8>>,
+    restore: unsafe fn(&mut World, NonNull<u8>),
+    drop: unsafe fn(NonNull<u8>),
+    scope: fn(&mut World, &mut ScopeFn) -> bool,
+    /// Registers the resource as the supertraits of the trait it was registered as, if it was registered as them.
+    register_supertraits: Option<fn(&mut World)>,
+}
+
+impl Copy for ResourceMover {}
+impl Clone for ResourceMover {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl ResourceMover {
+    fn new<R: Resource>() -> Self {
+        Self {
+            take: Self::take::<R>,
+            restore: Self::restore::<R>,
+            drop: Self::drop::<R>,
+            scope: Self::scope::<R>,
+            register_supertraits: None,
+        }
+    }
+
+    /// Same as [`ResourceMover::new`], for a resource that is also registered as the supertraits of `Trait`.
+    fn with_supertraits<Trait: ?Sized + TraitResource, R: Resource>() -> Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        Self {
+            register_supertraits: Some(<(R,) as TraitResourceMarker<Trait>>::register_supertraits),
+            ..Self::new::<R>()
+        }
+    }
+
+    /// Runs `f` with the resource removed from the world, through [`World::resource_scope`].
+    /// Returns `false` if the resource does not exist.
+    fn scope<R: Resource>(world: &mut World, f: &mut ScopeFn) -> bool {
+        if !world.contains_resource::<R>() {
+            return false;
+        }
+
+        world.resource_scope(|world, mut resource: Mut<R>| f(world, PtrMut::from(&mut *resource)));
+        true
+    }
+
+    /// Removes the resource from the world and leaks it into a box.
+    fn take<R: Resource>(world: &mut World) -> Option<NonNull<u8>> {
+        world
+            .remove_resource::<R>()
+            .map(|resource| NonNull::from(Box::leak(Box::new(resource))).cast())
+    }
+
+    /// # Safety
+    /// `ptr` must have been returned by [`ResourceMover::take`] for the same resource.
+    unsafe fn restore<R: Resource>(world: &mut World, ptr: NonNull<u8>) {
+        world.insert_resource(*Box::from_raw(ptr.as_ptr() as *mut R));
+    }
+
+    /// # Safety
+    /// `ptr` must have been returned by [`ResourceMover::take`] for the same resource.
+    unsafe fn drop<R: Resource>(ptr: NonNull<u8>) {
+        drop(Box::from_raw(ptr.as_ptr() as *mut R));
     }
 }
 
 struct TraitData<Trait: ?Sized> {
     resource_component_id: ComponentId,
     trait_ptr: DynCtor<Trait>,
+    /// `None` for resources that can not be moved out of the world.
+    mover: Option<ResourceMover>,
+    /// Disabled resources are skipped by the iterators, but keep their place in the registry.
+    enabled: bool,
+    /// The last result of the run condition of the registration, `true` without a condition.
+    condition_met: bool,
+    /// Whether the resource was registered as the trait itself, and not only as a supertrait.
+    explicit: bool,
+    /// Number of registrations as subtraits that registered the resource as this trait.
+    implied_by: usize,
+    /// Whether the registration also registered the resource as the supertraits of the trait.
+    supertraits: bool,
+}
+
+impl<Trait: ?Sized> TraitData<Trait> {
+    /// An enabled, explicit registration without a run condition that does not register supertraits.
+    fn new(resource_component_id: ComponentId, trait_ptr: DynCtor<Trait>, mover: Option<ResourceMover>) -> Self {
+        Self {
+            resource_component_id,
+            trait_ptr,
+            mover,
+            enabled: true,
+            condition_met: true,
+            explicit: true,
+            implied_by: 0,
+            supertraits: false,
+        }
+    }
+
+    /// Whether the iterators should return the resource.
+    fn is_active(&self) -> bool {
+        self.enabled && self.condition_met
+    }
+
+    /// Removes the resource from the world while `f` runs and inserts it back afterwards,
+    /// like [`World::resource_scope`].
+    /// Returns `None` if the resource does not exist or can not be moved.
+    /// # Panics
+    /// Panics if `f` inserts the same resource into the world.
+    fn scope<U>(self, world: &mut World, f: impl FnOnce(&mut World, &mut Trait) -> U) -> Option<U> {
+        let mover = self.mover?;
+        let mut f = Some(f);
+        let mut output = None;
+        (mover.scope)(world, &mut |world, ptr| {
+            let f = f.take().expect("The scope runs once");
+            // SAFETY: The pointer points to the resource the cast was registered for.
+            output = Some(f(world, unsafe { self.trait_ptr.cast_mut(ptr) }));
+        });
+        output
+    }
 }
 
 impl<T: ?Sized> Copy for TraitData<T> {}
@@ -217,14 +604,43 @@ impl<T: ?Sized> Clone for TraitData<T> {
     }
 }
 
+/// The resources registered as `Trait`, in registration order.
+/// Inserted with the first registration and removed with the last unregistration.
 #[derive(Resource)]
-struct TraitResourceRegistry<Trait: ?Sized> {
-    trait_data: Vec<TraitData<Trait>>,
+pub(crate) struct TraitResourceRegistry<Trait: ?Sized> {
+    /// Registered data in registration order. Unregistering leaves an empty slot,
+    /// so the other slots keep their index until the registry is compacted.
+    slots: Vec<Option<TraitData<Trait>>>,
+    /// Slot index of every registered resource.
+    index: HashMap<ComponentId, usize>,
+    /// Run conditions of registrations, by resource component id.
+    conditions: HashMap<ComponentId, BoxedCondition>,
 }
 
 impl<Trait: ?Sized> TraitResourceRegistry<Trait> {
     fn empty() -> Self {
-        Self { trait_data: vec![] }
+        Self {
+            slots: vec![],
+            index: HashMap::default(),
+            conditions: HashMap::default(),
+        }
+    }
+
+    /// Number of registered resources, including disabled ones.
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Component ids of the resources that are enabled and whose run condition was met, in registry order.
+    fn active_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.iter()
+            .filter(|data| data.is_active())
+            .map(|data| data.resource_component_id)
+    }
+
+    /// The registered data, in registry order.
+    fn iter(&self) -> impl Iterator<Item = &TraitData<Trait>> {
+        self.slots.iter().flatten()
     }
 }
 
@@ -236,70 +652,170 @@ impl<Trait: ?Sized> Default for TraitResourceRegistry<Trait> {
 
 impl<Trait: ?Sized + TraitResource> TraitResourceRegistry<Trait> {
     /// Registers data for resource trait.
-    /// Overrides data with same component id.
-    fn register(&mut self, trait_data: TraitData<Trait>) {
-        let exists_in_index = self
-            .trait_data
-            .iter()
-            .position(|data| data.resource_component_id == trait_data.resource_component_id);
+    /// Overrides data with same component id, but keeps whether it is enabled, its run condition
+    /// and the registrations as subtraits.
+    /// Returns `true` if the resource was not registered before.
+    fn register(&mut self, mut trait_data: TraitData<Trait>) -> bool {
+        if let Some(existing) = self.get_mut(trait_data.resource_component_id) {
+            trait_data.enabled = existing.enabled;
+            trait_data.condition_met = existing.condition_met;
+            trait_data.explicit |= existing.explicit;
+            trait_data.implied_by = existing.implied_by;
+            trait_data.supertraits = existing.supertraits;
+            *existing = trait_data;
+            false
+        } else {
+            self.insert(trait_data);
+            true
+        }
+    }
 
-        if let Some(index) = exists_in_index {
-            self.trait_data[index] = trait_data;
+    /// Registers data for resource trait because it was registered as a subtrait.
+    /// Existing data is kept.
+    /// Returns `true` if the resource was not registered before.
+    fn register_implied(&mut self, trait_data: TraitData<Trait>) -> bool {
+        if let Some(existing) = self.get_mut(trait_data.resource_component_id) {
+            existing.implied_by += 1;
+            false
         } else {
-            self.trait_data.push(trait_data);
+            self.insert(TraitData { explicit: false, implied_by: 1, ..trait_data });
+            true
         }
     }
 
+    fn insert(&mut self, trait_data: TraitData<Trait>) {
+        self.index.insert(trait_data.resource_component_id, self.slots.len());
+        self.slots.push(Some(trait_data));
+    }
+
+    /// Get the data registered for the component.
+    fn get(&self, resource_component_id: ComponentId) -> Option<&TraitData<Trait>> {
+        let slot = *self.index.get(&resource_component_id)?;
+        self.slots[slot].as_ref()
+    }
+
+    /// Get the data registered for the component.
+    fn get_mut(&mut self, resource_component_id: ComponentId) -> Option<&mut TraitData<Trait>> {
+        let slot = *self.index.get(&resource_component_id)?;
+        self.slots[slot].as_mut()
+    }
+
+    /// Get the next registered data at or after `cursor` and move the cursor past it.
+    fn next_registered(&self, cursor: &mut usize) -> Option<TraitData<Trait>> {
+        let (slot, data) = self
+            .slots
+            .iter()
+            .enumerate()
+            .skip(*cursor)
+            .find_map(|(slot, data)| Some((slot, (*data)?)))?;
+
+        *cursor = slot + 1;
+        Some(data)
+    }
+
+    /// Get the next active data at or after `cursor` and move the cursor past it.
+    fn next_active(&self, cursor: &mut usize) -> Option<TraitData<Trait>> {
+        let (slot, data) = self
+            .slots
+            .iter()
+            .enumerate()
+            .skip(*cursor)
+            .find_map(|(slot, data)| Some((slot, (*data)?)).filter(|(_, data)| data.is_active()))?;
+
+        *cursor = slot + 1;
+        Some(data)
+    }
+
     /// Unregister the component from the registry.
     /// Returns the new length of the registry.
     fn unregister(&mut self, resource_component_id: ComponentId) -> usize {
-        self.trait_data.retain(|data| data.resource_component_id != resource_component_id);
-        self.trait_data.len()
+        self.conditions.remove(&resource_component_id);
+        if let Some(slot) = self.index.remove(&resource_component_id) {
+            self.slots[slot] = None;
+        }
+
+        // Compact once at least half of the slots are empty, which keeps unregistering amortized constant.
+        if self.slots.len() >= 2 * self.index.len() {
+            self.slots.retain(Option::is_some);
+            for (slot, data) in self.slots.iter().flatten().enumerate() {
+                self.index.insert(data.resource_component_id, slot);
+            }
+        }
+
+        self.len()
+    }
+}
+
+/// The info of a resource that is registered as a trait.
+fn resource_info(components: &Components, resource_component_id: ComponentId) -> &ComponentInfo {
+    components
+        .get_info(resource_component_id)
+        .expect("Registered resources have component info")
+}
+
+/// Registry of non-send resources.
+/// Kept apart from [`TraitResourceRegistry`] so every resource registered there is `Send`.
+#[derive(Resource)]
+struct NonSendTraitResourceRegistry<Trait: ?Sized>(TraitResourceRegistry<Trait>);
+
+impl<Trait: ?Sized> Default for NonSendTraitResourceRegistry<Trait> {
+    fn default() -> Self {
+        Self(TraitResourceRegistry::empty())
     }
 }
 
 /// An [`Iterator`] over resources as mutables that implements a trait.
 pub struct TraitResourceIteratorMut<'w, Trait: ?Sized + TraitResource> {
-    world: &'w mut World,
+    registry: Option<&'w TraitResourceRegistry<Trait>>,
+    world: UnsafeWorldCell<'w>,
     cursor: usize,
-    _marker: PhantomData<Trait>
+    collection: std::slice::IterMut<'w, collection::CollectionEntry<Trait>>,
+    collection_id: Option<ComponentId>,
 }
 
 impl<'w, Trait: ?Sized + TraitResource> TraitResourceIteratorMut<'w, Trait> {
     fn new(world: &'w mut World) -> Self {
+        // SAFETY: We have exclusive access to the world.
+        unsafe { Self::new_unchecked(world.as_unsafe_world_cell()) }
+    }
+
+    /// # Safety
+    /// The caller must ensure that nothing else accesses the registry of `Trait` mutably
+    /// or the resources registered in it or the [`TraitCollection<Trait>`] during the lifetime `'w`.
+    unsafe fn new_unchecked(world: UnsafeWorldCell<'w>) -> Self {
+        let collection = world
+            .get_resource_mut::<TraitCollection<Trait>>()
+            .map_or_else(Default::default, |collection| collection.into_inner().entries.iter_mut());
+
         Self {
+            registry: world.get_resource::<TraitResourceRegistry<Trait>>(),
             world,
             cursor: 0,
-            _marker: PhantomData
+            collection,
+            collection_id: world.components().resource_id::<TraitCollection<Trait>>(),
         }
     }
 }
 
 impl<'w, Trait: ?Sized + TraitResource> Iterator for TraitResourceIteratorMut<'w, Trait> {
-    type Item = Option<&'w mut Trait>;
+    type Item = Option<TraitResourceMut<'w, Trait>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // TODO: Avoid getting resource every next call? (This is done because we cant have two mutable borrows of world).
-        if let Some(registry) = self.world.get_resource_mut::<TraitResourceRegistry<Trait>>()  {
-            if self.cursor >= registry.trait_data.len() {
-                return None;
-            }
+        let Some(data) = self.registry.and_then(|registry| registry.next_active(&mut self.cursor)) else {
+            let collection_id = self.collection_id?;
+            return self.collection.next().map(|entry| {
+                Some(TraitResourceMut::from_collection(entry.value.as_mut(), collection_id, entry.type_id, entry.type_name, self.world))
+            });
+        };
 
-            let data = registry.trait_data[self.cursor];
-            self.cursor += 1;
-
-            if let Some(mut ptr) = self.world.get_resource_mut_by_id(data.resource_component_id) {
-                // SAFETY: We know that the pointer is valid because we got it from the world.
-                // We are also guaranteed that there is no duplicates of resource_component_id and resources
-                // and we can therefore "guarantee" that the iterator will only return one mutable
-                // reference to a single resource. So this should be OK?
-                let raw_ptr = unsafe { data.trait_ptr.cast_mut(ptr.as_mut()) as *mut Trait };
-                Some(Some(unsafe { &mut *raw_ptr }))
-            } else {
-                Some(None)
-            }
+        // SAFETY: The registry has no duplicates of resource_component_id, so the iterator will only
+        // return one mutable reference to a single resource. Access to the registered resources is
+        // guaranteed by the constructor.
+        if let Some(ptr) = unsafe { self.world.get_resource_mut_by_id(data.resource_component_id) } {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            Some(Some(unsafe { TraitResourceMut::from_resource(ptr.into_inner(), data.trait_ptr, info, self.world) }))
         } else {
-            None
+            Some(None)
         }
     }
 }
@@ -309,120 +825,1069 @@ pub struct TraitResourceIterator<'w, Trait: ?Sized + TraitResource> {
     registry: Option<&'w TraitResourceRegistry<Trait>>,
     world: &'w World,
     cursor: usize,
+    collection: std::slice::Iter<'w, collection::CollectionEntry<Trait>>,
+    collection_id: Option<ComponentId>,
 }
 
 impl<'w, Trait: ?Sized + TraitResource> TraitResourceIterator<'w, Trait> {
     fn new(world: &'w World) -> Self {
+        let collection = world
+            .get_resource::<TraitCollection<Trait>>()
+            .map_or_else(Default::default, |collection| collection.entries.iter());
+
         Self {
             registry: world.get_resource::<TraitResourceRegistry<Trait>>(),
             world,
             cursor: 0,
+            collection,
+            collection_id: world.components().resource_id::<TraitCollection<Trait>>(),
         }
     }
 }
 
 impl<'w, Trait: ?Sized + TraitResource> Iterator for TraitResourceIterator<'w, Trait> {
-    type Item = Option<&'w Trait>;
+    type Item = Option<TraitResourceRef<'w, Trait>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(registry) = self.registry {
-            if self.cursor >= registry.trait_data.len() {
-                return None
-            }
-
-            let data = registry.trait_data[self.cursor];
-            self.cursor += 1;
+        let Some(data) = self.registry.and_then(|registry| registry.next_active(&mut self.cursor)) else {
+            let collection_id = self.collection_id?;
+            return self.collection.next().map(|entry| {
+                Some(TraitResourceRef::from_collection(
+                    entry.value.as_ref(),
+                    collection_id,
+                    entry.type_id,
+                    entry.type_name,
+                    self.world.as_unsafe_world_cell_readonly(),
+                ))
+            });
+        };
 
-            if let Some(ptr) = self.world.get_resource_by_id(data.resource_component_id) {
-                Some(Some(unsafe { data.trait_ptr.cast(ptr) }))
-            } else {
-                Some(None)
-            }
+        if let Some(ptr) = self.world.get_resource_by_id(data.resource_component_id) {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            let world = self.world.as_unsafe_world_cell_readonly();
+            Some(Some(unsafe { TraitResourceRef::from_resource(data.trait_ptr.cast(ptr), ptr, info, world) }))
         } else {
-            None
+            Some(None)
         }
     }
 }
 
-pub trait TraitResourceExt {
-    /// Inserts a resource into the world and registers its trait.
-    /// if the resource already exists, it will be overridden.
-    fn insert_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self, resource: R) -> &mut Self
-    where (R,): TraitResourceMarker<Trait, Covered = R>;
+/// An [`Iterator`] over resources that implements a trait, including disabled ones.
+/// Every item is paired with whether the resource is enabled.
+pub struct TraitResourceIteratorAll<'w, Trait: ?Sized + TraitResource> {
+    registry: Option<&'w TraitResourceRegistry<Trait>>,
+    world: &'w World,
+    cursor: usize,
+}
 
-    /// Initializes a resource into the world and registers its trait.
-    /// if the resource already exists, it will be overridden.
-    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + Default>(&mut self) -> &mut Self
-    where (R,): TraitResourceMarker<Trait, Covered = R>;
+impl<'w, Trait: ?Sized + TraitResource> TraitResourceIteratorAll<'w, Trait> {
+    fn new(world: &'w World) -> Self {
+        Self {
+            registry: world.get_resource::<TraitResourceRegistry<Trait>>(),
+            world,
+            cursor: 0,
+        }
+    }
+}
 
-    // ?: Should this maybe warn instead of panic?
-    /// Registers a resource as implementing a trait.
-    /// If the resource is already registered nothing will happen.
-    /// # Panics 
-    /// Panics if the resource does not exist.
-    fn register_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self) -> &mut Self
-    where (R,): TraitResourceMarker<Trait, Covered = R>;
+impl<'w, Trait: ?Sized + TraitResource> Iterator for TraitResourceIteratorAll<'w, Trait> {
+    type Item = (Option<TraitResourceRef<'w, Trait>>, bool);
 
-    /// Get [`TraitResourceIterator<Trait>`]
-    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<Trait>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let registry = self.registry?;
+        let data = registry.next_registered(&mut self.cursor)?;
 
-    /// Get [`TraitResourceIteratorMut<Trait>`]
-    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<Trait>;
+        let resource = self.world.get_resource_by_id(data.resource_component_id).map(|ptr| {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            let world = self.world.as_unsafe_world_cell_readonly();
+            unsafe { TraitResourceRef::from_resource(data.trait_ptr.cast(ptr), ptr, info, world) }
+        });
 
-    /// Unregister a resource from trait.
-    fn unregister_resource_from_trait<Trait: ?Sized + TraitResource, R: Resource>(&mut self)
-    where (R,): TraitResourceMarker<Trait, Covered = R>;
+        Some((resource, data.enabled))
+    }
 }
 
-impl TraitResourceExt for World {
-    fn insert_resource_as<Trait: ?Sized + TraitResource, R: Resource>(
-        &mut self,
-        resource: R,
-    ) -> &mut Self
-    where
-        (R,): TraitResourceMarker<Trait, Covered = R>,
-    {
-        self.insert_resource(resource);
-        self.register_resource_as::<Trait, R>();
-        self
-    }
+/// An [`Iterator`] over resources that implements every trait of a [`TraitResourceTuple`].
+/// Resources are visited in the registry order of the first trait.
+pub struct TraitResourceIntersectionIterator<'w, Q: TraitResourceTuple> {
+    trait_data: Vec<(ComponentId, Q::Casts)>,
+    world: &'w World,
+    cursor: usize,
+}
 
-    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + Default>(&mut self) -> &mut Self
-    where
+impl<'w, Q: TraitResourceTuple> TraitResourceIntersectionIterator<'w, Q> {
+    fn new(world: &'w World) -> Self {
+        Self {
+            trait_data: Q::intersect(world),
+            world,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'w, Q: TraitResourceTuple> Iterator for TraitResourceIntersectionIterator<'w, Q> {
+    type Item = Option<Q::Item<'w>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (resource_component_id, casts) = *self.trait_data.get(self.cursor)?;
+        self.cursor += 1;
+
+        if let Some(ptr) = self.world.get_resource_by_id(resource_component_id) {
+            let info = resource_info(self.world.components(), resource_component_id);
+            let world = self.world.as_unsafe_world_cell_readonly();
+            // SAFETY: The casts come from registrations of the resource behind `resource_component_id`.
+            Some(Some(unsafe { Q::cast(casts, ptr, info, world) }))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// An [`Iterator`] over resources as mutables that implements every trait of a [`TraitResourceTuple`].
+/// Only the first trait is returned, the other traits are used as a filter.
+pub struct TraitResourceIntersectionIteratorMut<'w, Q: TraitResourceTuple> {
+    trait_data: Vec<(ComponentId, Q::Casts)>,
+    world: UnsafeWorldCell<'w>,
+    cursor: usize,
+}
+
+impl<'w, Q: TraitResourceTuple> TraitResourceIntersectionIteratorMut<'w, Q> {
+    fn new(world: &'w mut World) -> Self {
+        Self {
+            trait_data: Q::intersect(world),
+            world: world.as_unsafe_world_cell(),
+            cursor: 0,
+        }
+    }
+}
+
+impl<'w, Q: TraitResourceTuple> Iterator for TraitResourceIntersectionIteratorMut<'w, Q> {
+    type Item = Option<TraitResourceMut<'w, Q::First>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (resource_component_id, casts) = *self.trait_data.get(self.cursor)?;
+        self.cursor += 1;
+
+        // SAFETY: We have exclusive access to the world, and the registries have no duplicate component ids,
+        // so every resource is only returned once.
+        if let Some(ptr) = unsafe { self.world.get_resource_mut_by_id(resource_component_id) } {
+            let info = resource_info(self.world.components(), resource_component_id);
+            Some(Some(unsafe { TraitResourceMut::from_resource(ptr.into_inner(), Q::first(casts).dyn_ctor(), info, self.world) }))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// An [`Iterator`] over non-send resources that implements a trait.
+/// # Panics
+/// Panics when a resource is accessed from another thread than the one the world was created on.
+pub struct NonSendTraitResourceIterator<'w, Trait: ?Sized + TraitResource> {
+    registry: Option<&'w NonSendTraitResourceRegistry<Trait>>,
+    world: &'w World,
+    cursor: usize,
+}
+
+impl<'w, Trait: ?Sized + TraitResource> NonSendTraitResourceIterator<'w, Trait> {
+    fn new(world: &'w World) -> Self {
+        Self {
+            registry: world.get_resource::<NonSendTraitResourceRegistry<Trait>>(),
+            world,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'w, Trait: ?Sized + TraitResource> Iterator for NonSendTraitResourceIterator<'w, Trait> {
+    type Item = Option<TraitResourceRef<'w, Trait>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let registry = self.registry?;
+        let data = registry.0.next_active(&mut self.cursor)?;
+
+        if let Some(ptr) = self.world.get_non_send_by_id(data.resource_component_id) {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            let world = self.world.as_unsafe_world_cell_readonly();
+            Some(Some(unsafe { TraitResourceRef::from_non_send(data.trait_ptr.cast(ptr), ptr, info, world) }))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// An [`Iterator`] over non-send resources as mutables that implements a trait.
+/// # Panics
+/// Panics when a resource is accessed from another thread than the one the world was created on.
+pub struct NonSendTraitResourceIteratorMut<'w, Trait: ?Sized + TraitResource> {
+    registry: Option<&'w NonSendTraitResourceRegistry<Trait>>,
+    world: UnsafeWorldCell<'w>,
+    cursor: usize,
+}
+
+impl<'w, Trait: ?Sized + TraitResource> NonSendTraitResourceIteratorMut<'w, Trait> {
+    fn new(world: &'w mut World) -> Self {
+        let world = world.as_unsafe_world_cell();
+        Self {
+            // SAFETY: We have exclusive access to the world and the registry is only read.
+            registry: unsafe { world.get_resource::<NonSendTraitResourceRegistry<Trait>>() },
+            world,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'w, Trait: ?Sized + TraitResource> Iterator for NonSendTraitResourceIteratorMut<'w, Trait> {
+    type Item = Option<TraitResourceMut<'w, Trait>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let registry = self.registry?;
+        let data = registry.0.next_active(&mut self.cursor)?;
+
+        // SAFETY: The registry has no duplicates of resource_component_id, so the iterator will only
+        // return one mutable reference to a single resource.
+        if let Some(ptr) = unsafe { self.world.get_non_send_resource_mut_by_id(data.resource_component_id) } {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            Some(Some(unsafe { TraitResourceMut::from_non_send(ptr.into_inner(), data.trait_ptr, info, self.world) }))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// An error returned when accessing trait resources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraitResourceError {
+    /// A resource is registered in both registries of a split borrow.
+    OverlappingResource {
+        component_id: ComponentId,
+        name: String,
+    },
+    /// No resource exists for the component id.
+    NonexistentResource {
+        component_id: ComponentId,
+    },
+    /// The resource of the component id is not of the expected type.
+    MismatchedType {
+        component_id: ComponentId,
+        expected: &'static str,
+        found: String,
+    },
+}
+
+impl fmt::Display for TraitResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OverlappingResource { name, .. } => {
+                write!(f, "resource `{name}` is registered as both traits")
+            }
+            Self::NonexistentResource { component_id } => {
+                write!(f, "no resource exists for component id {component_id:?}")
+            }
+            Self::MismatchedType { expected, found, .. } => {
+                write!(f, "expected resource of type `{expected}`, found `{found}`")
+            }
+        }
+    }
+}
+
+impl Error for TraitResourceError {}
+
+/// Refers to one resource registered as `Trait` in a specific world.
+/// It can be stored in components or other resources, and is resolved with [`TraitResourceExt::get_by_handle`].
+pub struct TraitResourceHandle<Trait: ?Sized> {
+    world_id: WorldId,
+    resource_component_id: ComponentId,
+    trait_ptr: DynCtor<Trait>,
+}
+
+impl<Trait: ?Sized> TraitResourceHandle<Trait> {
+    fn new(world_id: WorldId, data: &TraitData<Trait>) -> Self {
+        Self {
+            world_id,
+            resource_component_id: data.resource_component_id,
+            trait_ptr: data.trait_ptr,
+        }
+    }
+
+    /// The component id of the resource.
+    pub fn component_id(&self) -> ComponentId {
+        self.resource_component_id
+    }
+}
+
+impl<Trait: ?Sized + TraitResource> TraitResourceHandle<Trait> {
+    /// Whether the handle points at a resource that is still registered as `Trait`.
+    fn is_registered_in(&self, world: &World) -> bool {
+        self.world_id == world.id()
+            && world
+                .get_resource::<TraitResourceRegistry<Trait>>()
+                .is_some_and(|registry| registry.get(self.resource_component_id).is_some())
+    }
+}
+
+impl<T: ?Sized> Copy for TraitResourceHandle<T> {}
+impl<T: ?Sized> Clone for TraitResourceHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> PartialEq for TraitResourceHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.world_id == other.world_id && self.resource_component_id == other.resource_component_id
+    }
+}
+
+impl<T: ?Sized> Eq for TraitResourceHandle<T> {}
+
+impl<T: ?Sized> std::hash::Hash for TraitResourceHandle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.world_id.hash(state);
+        self.resource_component_id.hash(state);
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TraitResourceHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraitResourceHandle")
+            .field("world_id", &self.world_id)
+            .field("resource_component_id", &self.resource_component_id)
+            .finish()
+    }
+}
+
+/// A resource removed from the world by [`TraitResourceExt::take_resources_trait`].
+/// It derefs to the trait and is inserted back with [`TraitResourceExt::restore_resources_trait`],
+/// the resource is dropped if it is not restored.
+pub struct TakenTraitResource<Trait: ?Sized> {
+    handle: TraitResourceHandle<Trait>,
+    mover: ResourceMover,
+    /// A leaked box of the resource of the handle.
+    ptr: NonNull<u8>,
+}
+
+// SAFETY: The pointer owns a resource, which is `Send + Sync`.
+unsafe impl<Trait: ?Sized> Send for TakenTraitResource<Trait> {}
+unsafe impl<Trait: ?Sized> Sync for TakenTraitResource<Trait> {}
+
+impl<Trait: ?Sized> TakenTraitResource<Trait> {
+    /// The handle of the resource in the world it was taken from.
+    pub fn handle(&self) -> TraitResourceHandle<Trait> {
+        self.handle
+    }
+}
+
+impl<Trait: ?Sized> std::ops::Deref for TakenTraitResource<Trait> {
+    type Target = Trait;
+
+    fn deref(&self) -> &Trait {
+        // SAFETY: The pointer points to the resource the cast of the handle was registered for.
+        unsafe { self.handle.trait_ptr.cast(Ptr::new(self.ptr)) }
+    }
+}
+
+impl<Trait: ?Sized> std::ops::DerefMut for TakenTraitResource<Trait> {
+    fn deref_mut(&mut self) -> &mut Trait {
+        // SAFETY: The pointer points to the resource the cast of the handle was registered for, and is owned by `self`.
+        unsafe { self.handle.trait_ptr.cast_mut(PtrMut::new(self.ptr)) }
+    }
+}
+
+impl<Trait: ?Sized> Drop for TakenTraitResource<Trait> {
+    fn drop(&mut self) {
+        // SAFETY: The pointer was returned by the take function of the same mover.
+        unsafe { (self.mover.drop)(self.ptr) }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TakenTraitResource<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakenTraitResource")
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+pub trait TraitResourceExt {
+    /// Inserts a resource into the world and registers its trait.
+    /// if the resource already exists, it will be overridden.
+    fn insert_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self, resource: R) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Initializes a resource into the world and registers its trait.
+    /// If the resource already exists it is kept, but still registered.
+    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Initializes a resource into the world and registers its trait.
+    /// if the resource already exists, it will be overridden.
+    fn replace_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Inserts a resource into the world and registers it as all of its [`TraitResources`].
+    /// if the resource already exists, it will be overridden.
+    fn insert_trait_resources<R: TraitResources>(&mut self, resource: R) -> &mut Self;
+
+    /// Initializes a resource into the world and registers it as all of its [`TraitResources`].
+    /// If the resource already exists it is kept, but still registered.
+    fn init_trait_resources<R: TraitResources + FromWorld>(&mut self) -> &mut Self;
+
+    // ?: Should this maybe warn instead of panic?
+    /// Registers a resource as implementing a trait.
+    /// If the resource is already registered nothing will happen.
+    /// The resource is also registered as the supertraits listed in `#[trait_resource(supertraits(...))]`.
+    /// # Panics 
+    /// Panics if the resource does not exist.
+    fn register_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Registers a resource as a trait through a part of it, like a field or a wrapped value.
+    /// `project` is used for shared access and `project_mut` for mutable access.
+    /// If the resource is already registered, its projection is replaced.
+    /// The resource is not registered as the supertraits of the trait, because they are implemented by the part and not by `R`.
+    /// # Panics
+    /// Panics if the resource does not exist.
+    fn register_resource_as_with<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        project: fn(&R) -> &Trait,
+        project_mut: fn(&mut R) -> &mut Trait,
+    ) -> &mut Self;
+
+    /// Registers the resource of a component id as implementing a trait, for resources inserted with
+    /// [`World::insert_resource_by_id`]. The resource can not be moved out of the world, so it is skipped by
+    /// [`TraitResourceExt::trait_resource_scope`] and [`TraitResourceExt::take_resources_trait`].
+    /// If the resource is already registered, its cast is replaced.
+    /// # Safety
+    /// `cast` must turn a pointer to the resource into a pointer to the same value as the trait.
+    /// # Panics
+    /// Panics if the resource does not exist.
+    unsafe fn register_resource_by_id_as<Trait: ?Sized + TraitResource>(
+        &mut self,
+        component_id: ComponentId,
+        cast: unsafe fn(*mut u8) -> *mut Trait,
+    ) -> &mut Self;
+
+    /// Same as [`TraitResourceExt::register_resource_by_id_as`], but uses the cast of `R` after checking that
+    /// the component id is of type `R`.
+    fn try_register_resource_by_id_as<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Result<&mut Self, TraitResourceError>
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Inserts a non-send resource into the world and registers its trait.
+    /// if the resource already exists, it will be overridden.
+    fn insert_non_send_resource_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self, resource: R) -> &mut Self
+    where (R,): TraitNonSendMarker<Trait, Covered = R>;
+
+    /// Registers a non-send resource as implementing a trait.
+    /// Non-send resources are kept in a separate registry and are only returned by the non-send iterators.
+    /// # Panics
+    /// Panics if the resource does not exist.
+    fn register_non_send_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self) -> &mut Self
+    where (R,): TraitNonSendMarker<Trait, Covered = R>;
+
+    /// Unregister a non-send resource from trait.
+    fn unregister_non_send_from_trait<Trait: ?Sized + TraitResource, R: 'static>(&mut self)
+    where (R,): TraitNonSendMarker<Trait, Covered = R>;
+
+    /// Registers a resource as implementing a trait, but only while `condition` is met.
+    /// Conditions are updated by [`TraitResourceExt::update_trait_resource_conditions`], which
+    /// is called by every method that takes the world mutably, like [`TraitResourceExt::get_resources_trait_mut`].
+    /// The other iterators use the last result, and skip the resource until the condition ran for the first time.
+    /// Unlike [`TraitResourceExt::register_resource_as`], the resource is not registered as the supertraits of the trait,
+    /// because the condition only applies to `Trait`. Register it as the supertraits separately if needed.
+    /// # Panics
+    /// Panics if the resource does not exist.
+    fn register_resource_as_if<Trait: ?Sized + TraitResource, R: Resource, M>(&mut self, condition: impl Condition<M>) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Runs the run conditions of the resources registered as the trait.
+    fn update_trait_resource_conditions<Trait: ?Sized + TraitResource>(&mut self) -> &mut Self;
+
+    /// Adds a value to the [`TraitCollection<Trait>`], which is inserted if it does not exist.
+    /// See [`TraitCollection`] for the APIs that return its entries.
+    fn add_to_trait_collection<Trait: ?Sized + TraitResource, R: Resource>(&mut self, value: R) -> TraitCollectionId
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Get [`NonSendTraitResourceIterator<Trait>`]
+    fn get_non_send_resources_trait<Trait: ?Sized + TraitResource>(&self) -> NonSendTraitResourceIterator<'_, Trait>;
+
+    /// Get [`NonSendTraitResourceIteratorMut<Trait>`]
+    fn get_non_send_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> NonSendTraitResourceIteratorMut<'_, Trait>;
+
+    /// Get [`TraitResourceIterator<Trait>`]
+    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<'_, Trait>;
+
+    /// Get [`TraitResourceIteratorMut<Trait>`]
+    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<'_, Trait>;
+
+    /// Get [`TraitResourceIteratorAll<Trait>`], which also includes disabled resources.
+    fn get_all_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIteratorAll<'_, Trait>;
+
+    /// Get a handle to a resource registered as the trait, or `None` if it is not registered.
+    fn trait_resource_handle<Trait: ?Sized + TraitResource, R: Resource>(&self) -> Option<TraitResourceHandle<Trait>>
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Get handles to the existing resources registered as the trait that are not disabled and whose run condition
+    /// is met, in registry order. Unlike [`TraitResourceExt::get_resources_trait`], it skips the resources that do not
+    /// exist and the entries of the [`TraitCollection`], so use [`TraitResourceRef::handle`] to get the handle of an item.
+    fn trait_resource_handles<Trait: ?Sized + TraitResource>(&self) -> Vec<TraitResourceHandle<Trait>>;
+
+    /// Get the resource of a handle, even if it is disabled.
+    /// Returns `None` if the resource was removed, unregistered or the handle is from another world.
+    fn get_by_handle<Trait: ?Sized + TraitResource>(&self, handle: &TraitResourceHandle<Trait>) -> Option<&Trait>;
+
+    /// Same as [`TraitResourceExt::get_by_handle`], but mutable.
+    fn get_mut_by_handle<Trait: ?Sized + TraitResource>(&mut self, handle: &TraitResourceHandle<Trait>) -> Option<&mut Trait>;
+
+    /// Removes every existing resource registered as the trait, including disabled ones, in registry order.
+    /// The resources stay registered, so the iterators return `None` for them until they are restored.
+    /// Resources registered with [`TraitResourceExt::register_resource_as_with`] are taken as a whole.
+    fn take_resources_trait<Trait: ?Sized + TraitResource>(&mut self) -> Vec<TakenTraitResource<Trait>>;
+
+    /// Inserts resources returned by [`TraitResourceExt::take_resources_trait`] back into the world.
+    /// Resources that were unregistered in the meantime are registered again, in the order of `resources`,
+    /// and as the supertraits of the trait if they were registered as them before.
+    /// The resources are inserted again, so their change ticks are reset and they are detected as added.
+    /// # Panics
+    /// Panics if a resource was taken from another world.
+    fn restore_resources_trait<Trait: ?Sized + TraitResource>(&mut self, resources: Vec<TakenTraitResource<Trait>>) -> &mut Self;
+
+    /// Enables or disables a registered resource.
+    /// Disabled resources are skipped by the iterators but keep their place in the registry.
+    /// Nothing happens if the resource is not registered.
+    fn set_trait_resource_enabled<Trait: ?Sized + TraitResource, R: Resource>(&mut self, enabled: bool) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Selects a resource to be cloned into sub-app worlds by [`mirror_trait_resources::<Trait>`](mirror_trait_resources)
+    /// while it is registered as the trait.
+    fn mirror_resource_as<Trait: ?Sized + TraitResource, R: Resource + Clone>(&mut self) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Same as [`TraitResourceExt::mirror_resource_as`], but copies the resource with [`FromReflect`].
+    fn mirror_reflect_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromReflect>(&mut self) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Temporarily removes the first resource registered as the trait, in registry order,
+    /// and runs `f` with it and the world, through [`World::resource_scope`]. The resource is inserted
+    /// back afterwards, keeps its place in the registry and is marked as changed, like the items of
+    /// [`TraitResourceExt::get_resources_trait_mut`].
+    /// Returns `None` if no registered resource exists.
+    /// # Panics
+    /// Panics if `f` inserts a resource of the same type.
+    fn trait_resource_scope<Trait: ?Sized + TraitResource, U>(&mut self, f: impl FnOnce(&mut World, &mut Trait) -> U) -> Option<U>;
+
+    /// Like [`TraitResourceExt::trait_resource_scope`], but runs `f` for every resource registered as the trait.
+    fn for_each_trait_resource_scoped<Trait: ?Sized + TraitResource>(&mut self, f: impl FnMut(&mut World, &mut Trait));
+
+    /// Get a [`TraitResourceIteratorMut`] for two traits at the same time.
+    /// # Errors
+    /// Returns [`TraitResourceError::OverlappingResource`] if a resource is registered as both traits.
+    fn get_resources_trait_pair_mut<A: ?Sized + TraitResource, B: ?Sized + TraitResource>(
+        &mut self,
+    ) -> Result<(TraitResourceIteratorMut<'_, A>, TraitResourceIteratorMut<'_, B>), TraitResourceError>;
+
+    /// Get [`TraitResourceIntersectionIterator<Q>`] over resources registered as every trait of `Q`,
+    /// which is a tuple like `(&dyn A, &dyn B)`.
+    fn get_resources_traits<Q: TraitResourceTuple>(&self) -> TraitResourceIntersectionIterator<'_, Q>;
+
+    /// Get [`TraitResourceIntersectionIteratorMut<Q>`] over resources registered as every trait of `Q`,
+    /// which is a tuple like `(&dyn A, &dyn B)`.
+    fn get_resources_traits_mut<Q: TraitResourceTuple>(&mut self) -> TraitResourceIntersectionIteratorMut<'_, Q>;
+
+    /// Unregister a resource from trait.
+    /// The resource is also unregistered from the supertraits listed in `#[trait_resource(supertraits(...))]`,
+    /// unless it was registered as them directly or through another subtrait.
+    /// The resource stays registered as the trait while it is registered as a subtrait of it.
+    fn unregister_resource_from_trait<Trait: ?Sized + TraitResource, R: Resource>(&mut self)
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Unregister the resource of a component id from trait, without its supertraits.
+    fn unregister_resource_by_id_from_trait<Trait: ?Sized + TraitResource>(&mut self, component_id: ComponentId);
+}
+
+impl TraitResourceExt for World {
+    fn insert_resource_as<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        resource: R,
+    ) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.insert_resource(resource);
+        self.register_resource_as::<Trait, R>();
+        self
+    }
+
+    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.init_resource::<R>();
+        self.register_resource_as::<Trait, R>();
+        self
+    }
+
+    fn replace_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
+    where
         (R,): TraitResourceMarker<Trait, Covered = R>
     {
-        self.insert_resource_as::<Trait, R>(R::default());
+        let resource = R::from_world(self);
+        self.insert_resource_as::<Trait, R>(resource);
+        self
+    }
+
+    fn insert_trait_resources<R: TraitResources>(&mut self, resource: R) -> &mut Self {
+        self.insert_resource(resource);
+        R::register_all_traits(self);
+        self
+    }
+
+    fn init_trait_resources<R: TraitResources + FromWorld>(&mut self) -> &mut Self {
+        self.init_resource::<R>();
+        R::register_all_traits(self);
+        self
+    }
+
+    fn register_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        let resource_id = self
+            .components()
+            .resource_id::<R>()
+            .expect("Trying to register a nonexistent resource");
+
+        let resource_registry = self
+            .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+            .into_inner();
+
+        let trait_data = TraitData {
+            supertraits: true,
+            ..TraitData::new(resource_id, DynCtor::Cast(<(R,)>::cast), Some(ResourceMover::with_supertraits::<Trait, R>()))
+        };
+
+        // A registration with a run condition or a projection does not register the supertraits, so they are registered now.
+        let supertraits = resource_registry.get(resource_id).is_some_and(|data| data.supertraits);
+        resource_registry.register(trait_data);
+        if let Some(data) = resource_registry.get_mut(resource_id) {
+            data.supertraits = true;
+        }
+        if !supertraits {
+            <(R,) as TraitResourceMarker<Trait>>::register_supertraits(self);
+        }
+        self
+    }
+
+    fn register_resource_as_with<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        project: fn(&R) -> &Trait,
+        project_mut: fn(&mut R) -> &mut Trait,
+    ) -> &mut Self {
+        let resource_id = self
+            .components()
+            .resource_id::<R>()
+            .expect("Trying to register a nonexistent resource");
+
+        let resource_registry = self
+            .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+            .into_inner();
+
+        let trait_data = TraitData::new(resource_id, DynCtor::Projection(Projection::new(project, project_mut)), Some(ResourceMover::new::<R>()));
+
+        resource_registry.register(trait_data);
+        self
+    }
+
+    unsafe fn register_resource_by_id_as<Trait: ?Sized + TraitResource>(
+        &mut self,
+        component_id: ComponentId,
+        cast: unsafe fn(*mut u8) -> *mut Trait,
+    ) -> &mut Self {
+        assert!(
+            self.get_resource_by_id(component_id).is_some(),
+            "Trying to register a nonexistent resource"
+        );
+
+        let resource_registry = self
+            .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+            .into_inner();
+
+        let trait_data = TraitData::new(component_id, DynCtor::Cast(cast), None);
+
+        resource_registry.register(trait_data);
+        self
+    }
+
+    fn try_register_resource_by_id_as<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Result<&mut Self, TraitResourceError>
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        if self.get_resource_by_id(component_id).is_none() {
+            return Err(TraitResourceError::NonexistentResource { component_id });
+        }
+
+        let info = self
+            .components()
+            .get_info(component_id)
+            .expect("Existing resources have component info");
+
+        if info.type_id() != Some(TypeId::of::<R>()) {
+            return Err(TraitResourceError::MismatchedType {
+                component_id,
+                expected: std::any::type_name::<R>(),
+                found: info.name().to_owned(),
+            });
+        }
+
+        // SAFETY: The resource is of type `R`, which the cast is for.
+        unsafe { Ok(self.register_resource_by_id_as::<Trait>(component_id, <(R,)>::cast)) }
+    }
+
+    fn insert_non_send_resource_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self, resource: R) -> &mut Self
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>,
+    {
+        self.insert_non_send_resource(resource);
+        self.register_non_send_as::<Trait, R>();
+        self
+    }
+
+    fn register_non_send_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self) -> &mut Self
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>,
+    {
+        let resource_id = self
+            .components()
+            .get_resource_id(TypeId::of::<R>())
+            .expect("Trying to register a nonexistent non-send resource");
+
+        let resource_registry = self
+            .get_resource_or_insert_with::<NonSendTraitResourceRegistry<Trait>>(default)
+            .into_inner();
+
+        let trait_data = TraitData::new(resource_id, DynCtor::Cast(<(R,)>::cast), None);
+
+        resource_registry.0.register(trait_data);
+        self
+    }
+
+    fn unregister_non_send_from_trait<Trait: ?Sized + TraitResource, R: 'static>(&mut self)
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>
+    {
+        let resource_id_opt = self
+            .components()
+            .get_resource_id(TypeId::of::<R>());
+
+        if let Some(resource_id) = resource_id_opt {
+            if let Some(mut registry) = self.get_resource_mut::<NonSendTraitResourceRegistry<Trait>>() {
+                let new_size = registry.0.unregister(resource_id);
+                if new_size == 0 {
+                    self.remove_resource::<NonSendTraitResourceRegistry<Trait>>();
+                }
+            }
+        }
+    }
+
+    fn register_resource_as_if<Trait: ?Sized + TraitResource, R: Resource, M>(&mut self, condition: impl Condition<M>) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        let resource_id = self
+            .components()
+            .resource_id::<R>()
+            .expect("Trying to register a nonexistent resource");
+
+        // Like `run_if`, the condition only runs once it is needed, so it can read resources that are added later.
+        let mut condition: BoxedCondition = Box::new(IntoSystem::into_system(condition));
+        condition.initialize(self);
+
+        let registry = self
+            .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+            .into_inner();
+
+        // The condition can not be shared with the supertraits, so the resource is only registered as `Trait`.
+        registry.register(TraitData::new(resource_id, DynCtor::Cast(<(R,)>::cast), Some(ResourceMover::new::<R>())));
+        if let Some(data) = registry.get_mut(resource_id) {
+            data.condition_met = false;
+        }
+        registry.conditions.insert(resource_id, condition);
+        self
+    }
+
+    fn update_trait_resource_conditions<Trait: ?Sized + TraitResource>(&mut self) -> &mut Self {
+        let Some(mut registry) = self.get_resource_mut::<TraitResourceRegistry<Trait>>() else {
+            return self;
+        };
+
+        if registry.conditions.is_empty() {
+            return self;
+        }
+
+        // Conditions only read the world, so the registry can stay in it while they run.
+        let mut conditions = std::mem::take(&mut registry.bypass_change_detection().conditions);
+        let results: Vec<(ComponentId, bool)> = conditions
+            .iter_mut()
+            .map(|(&resource_component_id, condition)| (resource_component_id, condition.run_readonly((), self)))
+            .collect();
+
+        let mut registry = self.resource_mut::<TraitResourceRegistry<Trait>>();
+        let registry = registry.bypass_change_detection();
+        for (resource_component_id, condition_met) in results {
+            if let Some(data) = registry.get_mut(resource_component_id) {
+                data.condition_met = condition_met;
+            }
+        }
+        registry.conditions = conditions;
         self
     }
 
-    fn register_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self) -> &mut Self
+    fn add_to_trait_collection<Trait: ?Sized + TraitResource, R: Resource>(&mut self, value: R) -> TraitCollectionId
     where
         (R,): TraitResourceMarker<Trait, Covered = R>,
     {
-        let resource_id = self
-            .components()
-            .resource_id::<R>()
-            .expect("Trying to register a nonexistent resource");
+        self.get_resource_or_insert_with::<TraitCollection<Trait>>(default)
+            .push(value)
+    }
 
-        let resource_registry = self
-            .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
-            .into_inner();
+    fn get_non_send_resources_trait<Trait: ?Sized + TraitResource>(&self) -> NonSendTraitResourceIterator<'_, Trait> {
+        NonSendTraitResourceIterator::new(self)
+    }
 
-        let trait_data = TraitData {
-            resource_component_id: resource_id,
-            trait_ptr: DynCtor { cast: <(R,)>::cast },
+    fn get_non_send_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> NonSendTraitResourceIteratorMut<'_, Trait> {
+        NonSendTraitResourceIteratorMut::new(self)
+    }
+
+    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<'_, Trait> {
+        TraitResourceIterator::new(self)
+    }
+
+    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<'_, Trait> {
+        self.update_trait_resource_conditions::<Trait>();
+        TraitResourceIteratorMut::new(self)
+    }
+
+    fn get_all_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIteratorAll<'_, Trait> {
+        TraitResourceIteratorAll::new(self)
+    }
+
+    fn trait_resource_handle<Trait: ?Sized + TraitResource, R: Resource>(&self) -> Option<TraitResourceHandle<Trait>>
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        let resource_id = self.components().resource_id::<R>()?;
+        let data = self.get_resource::<TraitResourceRegistry<Trait>>()?.get(resource_id)?;
+        Some(TraitResourceHandle::new(self.id(), data))
+    }
+
+    fn trait_resource_handles<Trait: ?Sized + TraitResource>(&self) -> Vec<TraitResourceHandle<Trait>> {
+        let Some(registry) = self.get_resource::<TraitResourceRegistry<Trait>>() else {
+            return vec![];
         };
 
-        resource_registry.register(trait_data);
+        registry
+            .iter()
+            .filter(|data| data.is_active() && self.get_resource_by_id(data.resource_component_id).is_some())
+            .map(|data| TraitResourceHandle::new(self.id(), data))
+            .collect()
+    }
+
+    fn get_by_handle<Trait: ?Sized + TraitResource>(&self, handle: &TraitResourceHandle<Trait>) -> Option<&Trait> {
+        if !handle.is_registered_in(self) {
+            return None;
+        }
+
+        let ptr = self.get_resource_by_id(handle.resource_component_id)?;
+        // SAFETY: The handle is from this world and the resource is registered with its cast.
+        unsafe { Some(handle.trait_ptr.cast(ptr)) }
+    }
+
+    fn get_mut_by_handle<Trait: ?Sized + TraitResource>(&mut self, handle: &TraitResourceHandle<Trait>) -> Option<&mut Trait> {
+        if !handle.is_registered_in(self) {
+            return None;
+        }
+
+        let ptr = self.get_resource_mut_by_id(handle.resource_component_id)?.into_inner();
+        // SAFETY: The handle is from this world and the resource is registered with its cast.
+        unsafe { Some(handle.trait_ptr.cast_mut(ptr)) }
+    }
+
+    fn take_resources_trait<Trait: ?Sized + TraitResource>(&mut self) -> Vec<TakenTraitResource<Trait>> {
+        let Some(registry) = self.get_resource::<TraitResourceRegistry<Trait>>() else {
+            return vec![];
+        };
+
+        let trait_data: Vec<TraitData<Trait>> = registry.iter().copied().collect();
+        trait_data
+            .iter()
+            .filter_map(|data| {
+                let mover = data.mover?;
+                let ptr = (mover.take)(self)?;
+                Some(TakenTraitResource {
+                    handle: TraitResourceHandle::new(self.id(), data),
+                    mover,
+                    ptr,
+                })
+            })
+            .collect()
+    }
+
+    fn restore_resources_trait<Trait: ?Sized + TraitResource>(&mut self, resources: Vec<TakenTraitResource<Trait>>) -> &mut Self {
+        for resource in resources {
+            let handle = resource.handle;
+            assert!(handle.world_id == self.id(), "Trying to restore a resource into another world");
+            let mover = resource.mover;
+
+            let registry = self
+                .get_resource_or_insert_with::<TraitResourceRegistry<Trait>>(default)
+                .into_inner();
+
+            let registered = registry.get(handle.resource_component_id).is_some();
+            if !registered {
+                registry.register(TraitData {
+                    supertraits: mover.register_supertraits.is_some(),
+                    ..TraitData::new(handle.resource_component_id, handle.trait_ptr, Some(mover))
+                });
+            }
+
+            let resource = std::mem::ManuallyDrop::new(resource);
+            // SAFETY: The pointer was returned by the take function of the same mover, and is not dropped by `resource`.
+            unsafe { (mover.restore)(self, resource.ptr) };
+            if let Some(register_supertraits) = mover.register_supertraits.filter(|_| !registered) {
+                register_supertraits(self);
+            }
+        }
         self
     }
 
-    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<Trait> {
-        TraitResourceIterator::new(self)
+    fn set_trait_resource_enabled<Trait: ?Sized + TraitResource, R: Resource>(&mut self, enabled: bool) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        let Some(resource_id) = self.components().resource_id::<R>() else {
+            return self;
+        };
+
+        if let Some(mut registry) = self.get_resource_mut::<TraitResourceRegistry<Trait>>() {
+            if let Some(data) = registry.get_mut(resource_id) {
+                data.enabled = enabled;
+            }
+        }
+        self
     }
 
-    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<Trait> {
-        TraitResourceIteratorMut::new(self)
+    fn mirror_resource_as<Trait: ?Sized + TraitResource, R: Resource + Clone>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.get_resource_or_insert_with::<mirror::TraitResourceMirror<Trait>>(default)
+            .add::<R>(mirror::MirrorEntry::cloned::<Trait, R>());
+        self
+    }
+
+    fn mirror_reflect_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromReflect>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.get_resource_or_insert_with::<mirror::TraitResourceMirror<Trait>>(default)
+            .add::<R>(mirror::MirrorEntry::reflected::<Trait, R>());
+        self
+    }
+
+    fn trait_resource_scope<Trait: ?Sized + TraitResource, U>(&mut self, f: impl FnOnce(&mut World, &mut Trait) -> U) -> Option<U> {
+        self.update_trait_resource_conditions::<Trait>();
+        let data = self
+            .get_resource::<TraitResourceRegistry<Trait>>()?
+            .iter()
+            .find(|data| data.is_active() && data.mover.is_some() && self.get_resource_by_id(data.resource_component_id).is_some())
+            .copied()?;
+
+        data.scope(self, f)
+    }
+
+    fn for_each_trait_resource_scoped<Trait: ?Sized + TraitResource>(&mut self, mut f: impl FnMut(&mut World, &mut Trait)) {
+        self.update_trait_resource_conditions::<Trait>();
+        let Some(registry) = self.get_resource::<TraitResourceRegistry<Trait>>() else {
+            return;
+        };
+
+        let resource_component_ids: Vec<ComponentId> = registry
+            .iter()
+            .map(|data| data.resource_component_id)
+            .collect();
+
+        for resource_component_id in resource_component_ids {
+            // `f` may have unregistered the resource.
+            let data = self
+                .get_resource::<TraitResourceRegistry<Trait>>()
+                .and_then(|registry| registry.get(resource_component_id).copied())
+                .filter(|data| data.is_active());
+
+            if let Some(data) = data {
+                data.scope(self, &mut f);
+            }
+        }
+    }
+
+    fn get_resources_trait_pair_mut<A: ?Sized + TraitResource, B: ?Sized + TraitResource>(
+        &mut self,
+    ) -> Result<(TraitResourceIteratorMut<'_, A>, TraitResourceIteratorMut<'_, B>), TraitResourceError> {
+        self.update_trait_resource_conditions::<A>();
+        self.update_trait_resource_conditions::<B>();
+
+        if let (Some(registry_a), Some(registry_b)) = (
+            self.get_resource::<TraitResourceRegistry<A>>(),
+            self.get_resource::<TraitResourceRegistry<B>>(),
+        ) {
+            let overlap = registry_a
+                .iter()
+                .find(|data| registry_b.get(data.resource_component_id).is_some());
+
+            if let Some(data) = overlap {
+                let component_id = data.resource_component_id;
+                let name = self
+                    .components()
+                    .get_info(component_id)
+                    .map_or_else(|| format!("{component_id:?}"), |info| info.name().to_owned());
+
+                return Err(TraitResourceError::OverlappingResource { component_id, name });
+            }
+        }
+
+        if TypeId::of::<A>() == TypeId::of::<B>() && self.contains_resource::<TraitCollection<A>>() {
+            let component_id = self
+                .components()
+                .resource_id::<TraitCollection<A>>()
+                .expect("The collection exists");
+            let name = std::any::type_name::<TraitCollection<A>>().to_owned();
+
+            return Err(TraitResourceError::OverlappingResource { component_id, name });
+        }
+
+        let world = self.as_unsafe_world_cell();
+
+        // SAFETY: We have exclusive access to the world, the registries are only read,
+        // no resource is registered in both registries and the collections are different resources.
+        unsafe {
+            Ok((
+                TraitResourceIteratorMut::new_unchecked(world),
+                TraitResourceIteratorMut::new_unchecked(world),
+            ))
+        }
+    }
+
+    fn get_resources_traits<Q: TraitResourceTuple>(&self) -> TraitResourceIntersectionIterator<'_, Q> {
+        TraitResourceIntersectionIterator::new(self)
+    }
+
+    fn get_resources_traits_mut<Q: TraitResourceTuple>(&mut self) -> TraitResourceIntersectionIteratorMut<'_, Q> {
+        Q::update_conditions(self);
+        TraitResourceIntersectionIteratorMut::new(self)
     }
 
     // ? Should this maybe warn if the resource does not exists, because we should 
@@ -436,15 +1901,51 @@ impl TraitResourceExt for World {
             .components()
             .resource_id::<R>();
 
-        if let Some(resource_id) = resource_id_opt {
-            match self.get_resource_mut::<TraitResourceRegistry<Trait>>() {
-                Some(mut registry) => {
-                    let new_size = registry.unregister(resource_id);
-                    if new_size == 0 {
-                        self.remove_resource::<TraitResourceRegistry<Trait>>();
-                    }
-                },
-                _ => {}
+        let Some(resource_id) = resource_id_opt else {
+            return;
+        };
+
+        let Some(mut registry) = self.get_resource_mut::<TraitResourceRegistry<Trait>>() else {
+            return;
+        };
+
+        let Some(data) = registry.get_mut(resource_id) else {
+            return;
+        };
+
+        // Still registered as a supertrait of another trait, which only keeps its implied registration.
+        if data.implied_by > 0 {
+            let supertraits = data.supertraits;
+            *data = TraitData {
+                explicit: false,
+                implied_by: data.implied_by,
+                supertraits: true,
+                enabled: data.enabled,
+                ..TraitData::new(resource_id, DynCtor::Cast(<(R,)>::cast), Some(ResourceMover::with_supertraits::<Trait, R>()))
+            };
+            registry.conditions.remove(&resource_id);
+            if !supertraits {
+                <(R,) as TraitResourceMarker<Trait>>::register_supertraits(self);
+            }
+            return;
+        }
+
+        let supertraits = data.supertraits;
+        let new_size = registry.unregister(resource_id);
+        if new_size == 0 {
+            self.remove_resource::<TraitResourceRegistry<Trait>>();
+        }
+
+        if supertraits {
+            <(R,) as TraitResourceMarker<Trait>>::unregister_supertraits(self);
+        }
+    }
+
+    fn unregister_resource_by_id_from_trait<Trait: ?Sized + TraitResource>(&mut self, component_id: ComponentId) {
+        if let Some(mut registry) = self.get_resource_mut::<TraitResourceRegistry<Trait>>() {
+            let new_size = registry.unregister(component_id);
+            if new_size == 0 {
+                self.remove_resource::<TraitResourceRegistry<Trait>>();
             }
         }
     }
@@ -462,7 +1963,7 @@ impl TraitResourceExt for App {
         self
     }
 
-    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + Default>(&mut self) -> &mut Self
+    fn init_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
     where
         (R,): TraitResourceMarker<Trait, Covered = R>
     {
@@ -470,6 +1971,24 @@ impl TraitResourceExt for App {
         self
     }
 
+    fn replace_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromWorld>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.world.replace_resource_as::<Trait, R>();
+        self
+    }
+
+    fn insert_trait_resources<R: TraitResources>(&mut self, resource: R) -> &mut Self {
+        self.world.insert_trait_resources::<R>(resource);
+        self
+    }
+
+    fn init_trait_resources<R: TraitResources + FromWorld>(&mut self) -> &mut Self {
+        self.world.init_trait_resources::<R>();
+        self
+    }
+
     fn register_resource_as<Trait: ?Sized + TraitResource, R: Resource>(&mut self) -> &mut Self
     where
         (R,): TraitResourceMarker<Trait, Covered = R> 
@@ -478,18 +1997,248 @@ impl TraitResourceExt for App {
         self
     }
 
-    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<Trait> {
+    fn register_resource_as_with<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        project: fn(&R) -> &Trait,
+        project_mut: fn(&mut R) -> &mut Trait,
+    ) -> &mut Self {
+        self.world.register_resource_as_with::<Trait, R>(project, project_mut);
+        self
+    }
+
+    unsafe fn register_resource_by_id_as<Trait: ?Sized + TraitResource>(
+        &mut self,
+        component_id: ComponentId,
+        cast: unsafe fn(*mut u8) -> *mut Trait,
+    ) -> &mut Self {
+        self.world.register_resource_by_id_as::<Trait>(component_id, cast);
+        self
+    }
+
+    fn try_register_resource_by_id_as<Trait: ?Sized + TraitResource, R: Resource>(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Result<&mut Self, TraitResourceError>
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.world.try_register_resource_by_id_as::<Trait, R>(component_id)?;
+        Ok(self)
+    }
+
+    fn insert_non_send_resource_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self, resource: R) -> &mut Self
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>,
+    {
+        self.world.insert_non_send_resource_as::<Trait, R>(resource);
+        self
+    }
+
+    fn register_non_send_as<Trait: ?Sized + TraitResource, R: 'static>(&mut self) -> &mut Self
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>,
+    {
+        self.world.register_non_send_as::<Trait, R>();
+        self
+    }
+
+    fn unregister_non_send_from_trait<Trait: ?Sized + TraitResource, R: 'static>(&mut self)
+    where
+        (R,): TraitNonSendMarker<Trait, Covered = R>
+    {
+        self.world.unregister_non_send_from_trait::<Trait, R>();
+    }
+
+    fn register_resource_as_if<Trait: ?Sized + TraitResource, R: Resource, M>(&mut self, condition: impl Condition<M>) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.world.register_resource_as_if::<Trait, R, M>(condition);
+        self
+    }
+
+    fn update_trait_resource_conditions<Trait: ?Sized + TraitResource>(&mut self) -> &mut Self {
+        self.world.update_trait_resource_conditions::<Trait>();
+        self
+    }
+
+    fn add_to_trait_collection<Trait: ?Sized + TraitResource, R: Resource>(&mut self, value: R) -> TraitCollectionId
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.world.add_to_trait_collection::<Trait, R>(value)
+    }
+
+    fn get_non_send_resources_trait<Trait: ?Sized + TraitResource>(&self) -> NonSendTraitResourceIterator<'_, Trait> {
+        self.world.get_non_send_resources_trait::<Trait>()
+    }
+
+    fn get_non_send_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> NonSendTraitResourceIteratorMut<'_, Trait> {
+        self.world.get_non_send_resources_trait_mut::<Trait>()
+    }
+
+    fn get_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIterator<'_, Trait> {
         self.world.get_resources_trait::<Trait>()
     }
 
-    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<Trait> {
+    fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(&mut self) -> TraitResourceIteratorMut<'_, Trait> {
         self.world.get_resources_trait_mut::<Trait>()
     }
 
+    fn get_all_resources_trait<Trait: ?Sized + TraitResource>(&self) -> TraitResourceIteratorAll<'_, Trait> {
+        self.world.get_all_resources_trait::<Trait>()
+    }
+
+    fn trait_resource_handle<Trait: ?Sized + TraitResource, R: Resource>(&self) -> Option<TraitResourceHandle<Trait>>
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.world.trait_resource_handle::<Trait, R>()
+    }
+
+    fn trait_resource_handles<Trait: ?Sized + TraitResource>(&self) -> Vec<TraitResourceHandle<Trait>> {
+        self.world.trait_resource_handles::<Trait>()
+    }
+
+    fn get_by_handle<Trait: ?Sized + TraitResource>(&self, handle: &TraitResourceHandle<Trait>) -> Option<&Trait> {
+        self.world.get_by_handle(handle)
+    }
+
+    fn get_mut_by_handle<Trait: ?Sized + TraitResource>(&mut self, handle: &TraitResourceHandle<Trait>) -> Option<&mut Trait> {
+        self.world.get_mut_by_handle(handle)
+    }
+
+    fn take_resources_trait<Trait: ?Sized + TraitResource>(&mut self) -> Vec<TakenTraitResource<Trait>> {
+        self.world.take_resources_trait::<Trait>()
+    }
+
+    fn restore_resources_trait<Trait: ?Sized + TraitResource>(&mut self, resources: Vec<TakenTraitResource<Trait>>) -> &mut Self {
+        self.world.restore_resources_trait::<Trait>(resources);
+        self
+    }
+
+    fn set_trait_resource_enabled<Trait: ?Sized + TraitResource, R: Resource>(&mut self, enabled: bool) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.world.set_trait_resource_enabled::<Trait, R>(enabled);
+        self
+    }
+
+    fn mirror_resource_as<Trait: ?Sized + TraitResource, R: Resource + Clone>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.world.mirror_resource_as::<Trait, R>();
+        self
+    }
+
+    fn mirror_reflect_resource_as<Trait: ?Sized + TraitResource, R: Resource + FromReflect>(&mut self) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>
+    {
+        self.world.mirror_reflect_resource_as::<Trait, R>();
+        self
+    }
+
+    fn trait_resource_scope<Trait: ?Sized + TraitResource, U>(&mut self, f: impl FnOnce(&mut World, &mut Trait) -> U) -> Option<U> {
+        self.world.trait_resource_scope::<Trait, U>(f)
+    }
+
+    fn for_each_trait_resource_scoped<Trait: ?Sized + TraitResource>(&mut self, f: impl FnMut(&mut World, &mut Trait)) {
+        self.world.for_each_trait_resource_scoped::<Trait>(f);
+    }
+
+    fn get_resources_trait_pair_mut<A: ?Sized + TraitResource, B: ?Sized + TraitResource>(
+        &mut self,
+    ) -> Result<(TraitResourceIteratorMut<'_, A>, TraitResourceIteratorMut<'_, B>), TraitResourceError> {
+        self.world.get_resources_trait_pair_mut::<A, B>()
+    }
+
+    fn get_resources_traits<Q: TraitResourceTuple>(&self) -> TraitResourceIntersectionIterator<'_, Q> {
+        self.world.get_resources_traits::<Q>()
+    }
+
+    fn get_resources_traits_mut<Q: TraitResourceTuple>(&mut self) -> TraitResourceIntersectionIteratorMut<'_, Q> {
+        self.world.get_resources_traits_mut::<Q>()
+    }
+
     fn unregister_resource_from_trait<Trait: ?Sized + TraitResource, R: Resource>(&mut self)
     where
         (R,): TraitResourceMarker<Trait, Covered = R>
     {
         self.world.unregister_resource_from_trait::<Trait, R>();
     }
+
+    fn unregister_resource_by_id_from_trait<Trait: ?Sized + TraitResource>(&mut self, component_id: ComponentId) {
+        self.world.unregister_resource_by_id_from_trait::<Trait>(component_id);
+    }
+}
+
+pub trait TraitResourceAppExt {
+    /// Sends every event of type `E` to the resources registered as [`dyn EventListener<E>`](EventListener),
+    /// in registry order. Events are dispatched in [`PreUpdate`].
+    fn add_trait_event_dispatch<E: Event>(&mut self) -> &mut Self;
+
+    /// Registers a resource as implementing a trait when entering `state` and unregisters it when exiting it.
+    /// # Panics
+    /// The systems panic if the resource does not exist when entering the state.
+    fn register_resource_as_in_state<Trait: ?Sized + TraitResource, R: Resource, S: States>(&mut self, state: S) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Initializes a resource and registers its trait when entering `state`,
+    /// then unregisters and removes the resource when exiting it.
+    fn init_resource_as_in_state<Trait: ?Sized + TraitResource, R: Resource + FromWorld, S: States>(&mut self, state: S) -> &mut Self
+    where (R,): TraitResourceMarker<Trait, Covered = R>;
+
+    /// Mirrors the resources selected with [`TraitResourceExt::mirror_resource_as`] into the sub-app `label`
+    /// on every extract, see [`mirror_trait_resources`].
+    /// An empty sub-app is inserted if there is none with the label. An existing sub-app must call
+    /// [`extract_mirrored_trait_resources`] from its extract function.
+    fn mirror_trait_resources_to<Trait: ?Sized + TraitResource>(&mut self, label: impl AppLabel) -> &mut Self;
+}
+
+impl TraitResourceAppExt for App {
+    fn add_trait_event_dispatch<E: Event>(&mut self) -> &mut Self {
+        if self.world.contains_resource::<event::TraitEventDispatch<E>>() {
+            return self;
+        }
+
+        self.init_resource::<event::TraitEventDispatch<E>>();
+        self.add_event::<E>();
+        self.add_systems(PreUpdate, event::dispatch_trait_events::<E>);
+        self
+    }
+
+    fn register_resource_as_in_state<Trait: ?Sized + TraitResource, R: Resource, S: States>(&mut self, state: S) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.add_systems(OnEnter(state.clone()), |world: &mut World| {
+            world.register_resource_as::<Trait, R>();
+        });
+        self.add_systems(OnExit(state), |world: &mut World| {
+            world.unregister_resource_from_trait::<Trait, R>();
+        });
+        self
+    }
+
+    fn init_resource_as_in_state<Trait: ?Sized + TraitResource, R: Resource + FromWorld, S: States>(&mut self, state: S) -> &mut Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        self.add_systems(OnEnter(state.clone()), |world: &mut World| {
+            world.init_resource_as::<Trait, R>();
+        });
+        self.add_systems(OnExit(state), |world: &mut World| {
+            world.unregister_resource_from_trait::<Trait, R>();
+            world.remove_resource::<R>();
+        });
+        self
+    }
+
+    fn mirror_trait_resources_to<Trait: ?Sized + TraitResource>(&mut self, label: impl AppLabel) -> &mut Self {
+        mirror::mirror_trait_resources_to::<Trait>(self, label);
+        self
+    }
 }
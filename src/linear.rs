@@ -0,0 +1,150 @@
+//! The registry as it was before it was indexed by component id, a `Vec` searched linearly.
+//! Only compiled with the `bench` feature, so `benches/registry.rs` can compare both through the same world functions.
+
+use bevy::{
+    ecs::{component::ComponentId, schedule::BoxedCondition, world::unsafe_world_cell::UnsafeWorldCell},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{collection, resource_info, DynCtor, TraitCollection, TraitData, TraitResource, TraitResourceMut};
+
+#[derive(Resource)]
+struct LinearTraitResourceRegistry<Trait: ?Sized> {
+    trait_data: Vec<TraitData<Trait>>,
+    /// Run conditions of registrations, by resource component id.
+    conditions: HashMap<ComponentId, BoxedCondition>,
+}
+
+impl<Trait: ?Sized> Default for LinearTraitResourceRegistry<Trait> {
+    fn default() -> Self {
+        Self {
+            trait_data: vec![],
+            conditions: HashMap::default(),
+        }
+    }
+}
+
+impl<Trait: ?Sized + TraitResource> LinearTraitResourceRegistry<Trait> {
+    /// Registers data for resource trait.
+    /// Overrides data with same component id, but keeps whether it is enabled and its run condition.
+    fn register(&mut self, mut trait_data: TraitData<Trait>) {
+        let exists_in_index = self
+            .trait_data
+            .iter()
+            .position(|data| data.resource_component_id == trait_data.resource_component_id);
+
+        if let Some(index) = exists_in_index {
+            trait_data.enabled = self.trait_data[index].enabled;
+            trait_data.condition_met = self.trait_data[index].condition_met;
+            self.trait_data[index] = trait_data;
+        } else {
+            self.trait_data.push(trait_data);
+        }
+    }
+
+    /// Get the next active data at or after `cursor` and move the cursor past it.
+    fn next_active(&self, cursor: &mut usize) -> Option<TraitData<Trait>> {
+        let (index, data) = self
+            .trait_data
+            .iter()
+            .enumerate()
+            .skip(*cursor)
+            .find(|(_, data)| data.is_active())?;
+
+        *cursor = index + 1;
+        Some(*data)
+    }
+
+    /// Unregister the component from the registry.
+    /// Returns the new length of the registry.
+    fn unregister(&mut self, resource_component_id: ComponentId) -> usize {
+        self.conditions.remove(&resource_component_id);
+        self.trait_data.retain(|data| data.resource_component_id != resource_component_id);
+        self.trait_data.len()
+    }
+}
+
+/// [`TraitResourceExt::register_resource_by_id_as`](crate::TraitResourceExt::register_resource_by_id_as) with the linear registry.
+/// # Safety
+/// `cast` must turn a pointer to the resource into a pointer to the same value as the trait.
+pub unsafe fn register_resource_by_id_as<Trait: ?Sized + TraitResource>(
+    world: &mut World,
+    component_id: ComponentId,
+    cast: unsafe fn(*mut u8) -> *mut Trait,
+) {
+    assert!(
+        world.get_resource_by_id(component_id).is_some(),
+        "Trying to register a nonexistent resource"
+    );
+
+    let resource_registry = world
+        .get_resource_or_insert_with::<LinearTraitResourceRegistry<Trait>>(default)
+        .into_inner();
+
+    let trait_data = TraitData::new(component_id, DynCtor::Cast(cast), None);
+
+    resource_registry.register(trait_data);
+}
+
+/// [`TraitResourceExt::unregister_resource_by_id_from_trait`](crate::TraitResourceExt::unregister_resource_by_id_from_trait)
+/// with the linear registry.
+pub fn unregister_resource_by_id_from_trait<Trait: ?Sized + TraitResource>(world: &mut World, component_id: ComponentId) {
+    if let Some(mut registry) = world.get_resource_mut::<LinearTraitResourceRegistry<Trait>>() {
+        let new_size = registry.unregister(component_id);
+        if new_size == 0 {
+            world.remove_resource::<LinearTraitResourceRegistry<Trait>>();
+        }
+    }
+}
+
+/// [`TraitResourceExt::get_resources_trait_mut`](crate::TraitResourceExt::get_resources_trait_mut) with the linear registry.
+pub fn get_resources_trait_mut<Trait: ?Sized + TraitResource>(world: &mut World) -> LinearTraitResourceIteratorMut<'_, Trait> {
+    let world = world.as_unsafe_world_cell();
+
+    // SAFETY: We have exclusive access to the world.
+    unsafe {
+        let collection = world
+            .get_resource_mut::<TraitCollection<Trait>>()
+            .map_or_else(Default::default, |collection| collection.into_inner().entries.iter_mut());
+
+        LinearTraitResourceIteratorMut {
+            registry: world.get_resource::<LinearTraitResourceRegistry<Trait>>(),
+            world,
+            cursor: 0,
+            collection,
+            collection_id: world.components().resource_id::<TraitCollection<Trait>>(),
+        }
+    }
+}
+
+/// [`TraitResourceIteratorMut`](crate::TraitResourceIteratorMut) over the linear registry.
+pub struct LinearTraitResourceIteratorMut<'w, Trait: ?Sized + TraitResource> {
+    registry: Option<&'w LinearTraitResourceRegistry<Trait>>,
+    world: UnsafeWorldCell<'w>,
+    cursor: usize,
+    collection: std::slice::IterMut<'w, collection::CollectionEntry<Trait>>,
+    collection_id: Option<ComponentId>,
+}
+
+impl<'w, Trait: ?Sized + TraitResource> Iterator for LinearTraitResourceIteratorMut<'w, Trait> {
+    type Item = Option<TraitResourceMut<'w, Trait>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(data) = self.registry.and_then(|registry| registry.next_active(&mut self.cursor)) else {
+            let collection_id = self.collection_id?;
+            return self.collection.next().map(|entry| {
+                Some(TraitResourceMut::from_collection(entry.value.as_mut(), collection_id, entry.type_id, entry.type_name, self.world))
+            });
+        };
+
+        // SAFETY: The registry has no duplicates of resource_component_id, so the iterator will only
+        // return one mutable reference to a single resource.
+        if let Some(ptr) = unsafe { self.world.get_resource_mut_by_id(data.resource_component_id) } {
+            let info = resource_info(self.world.components(), data.resource_component_id);
+            Some(Some(unsafe { TraitResourceMut::from_resource(ptr.into_inner(), data.trait_ptr, info, self.world) }))
+        } else {
+            Some(None)
+        }
+    }
+}
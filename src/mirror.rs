@@ -0,0 +1,251 @@
+use std::{any::TypeId, marker::PhantomData};
+
+use bevy::{
+    app::{AppLabel, SubApp},
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{TraitResource, TraitResourceExt, TraitResourceMarker, TraitResourceRegistry};
+
+/// Copies a resource from the main world into a sub-app world and registers or removes it there.
+pub(crate) struct MirrorEntry {
+    /// Returns `None` if `main` does not contain the resource, otherwise whether it was inserted into `sub`.
+    copy: fn(&World, &mut World) -> Option<bool>,
+    /// Returns whether the resource was registered by this call.
+    register: fn(&mut World) -> bool,
+    unregister: fn(&mut World),
+    remove: fn(&mut World),
+}
+
+impl MirrorEntry {
+    pub(crate) fn cloned<Trait: ?Sized + TraitResource, R: Resource + Clone>() -> Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        Self {
+            copy: |main, sub| copy(sub, main.get_resource::<R>()?.clone()),
+            register: register::<Trait, R>,
+            unregister: |sub| sub.unregister_resource_from_trait::<Trait, R>(),
+            remove: |sub| drop(sub.remove_resource::<R>()),
+        }
+    }
+
+    pub(crate) fn reflected<Trait: ?Sized + TraitResource, R: Resource + FromReflect>() -> Self
+    where
+        (R,): TraitResourceMarker<Trait, Covered = R>,
+    {
+        Self {
+            copy: |main, sub| {
+                let resource = R::from_reflect(main.get_resource::<R>()?).expect("`FromReflect` failed to copy a mirrored resource");
+                copy(sub, resource)
+            },
+            register: register::<Trait, R>,
+            unregister: |sub| sub.unregister_resource_from_trait::<Trait, R>(),
+            remove: |sub| drop(sub.remove_resource::<R>()),
+        }
+    }
+}
+
+/// Updates the resource in place, or inserts it if `sub` does not contain it.
+fn copy<R: Resource>(sub: &mut World, resource: R) -> Option<bool> {
+    match sub.get_resource_mut::<R>() {
+        Some(mut existing) => {
+            *existing = resource;
+            Some(false)
+        }
+        None => {
+            sub.insert_resource(resource);
+            Some(true)
+        }
+    }
+}
+
+fn register<Trait: ?Sized + TraitResource, R: Resource>(sub: &mut World) -> bool
+where
+    (R,): TraitResourceMarker<Trait, Covered = R>,
+{
+    let registered = sub
+        .components()
+        .resource_id::<R>()
+        .zip(sub.get_resource::<TraitResourceRegistry<Trait>>())
+        .is_some_and(|(id, registry)| registry.get(id).is_some());
+
+    if !registered {
+        sub.register_resource_as::<Trait, R>();
+    }
+    !registered
+}
+
+/// Returns whether the resource of `type_id` is registered as `Trait` in `world`.
+fn registered_in<Trait: ?Sized + TraitResource>(world: &World, type_id: TypeId) -> bool {
+    world
+        .components()
+        .get_resource_id(type_id)
+        .zip(world.get_resource::<TraitResourceRegistry<Trait>>())
+        .is_some_and(|(id, registry)| registry.get(id).is_some())
+}
+
+/// The resources that are mirrored as `Trait`, by type id.
+#[derive(Resource)]
+pub(crate) struct TraitResourceMirror<Trait: ?Sized> {
+    entries: HashMap<TypeId, MirrorEntry>,
+    marker: PhantomData<fn(&Trait)>,
+}
+
+impl<Trait: ?Sized> Default for TraitResourceMirror<Trait> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Trait: ?Sized> TraitResourceMirror<Trait> {
+    pub(crate) fn add<R: Resource>(&mut self, entry: MirrorEntry) {
+        self.entries.insert(TypeId::of::<R>(), entry);
+    }
+}
+
+/// A resource copied into a sub-app world, and what the mirror did to it there.
+#[derive(Clone, Copy)]
+struct Mirrored {
+    type_id: TypeId,
+    inserted: bool,
+    registered: bool,
+}
+
+/// The resources mirrored as `Trait` into the sub-app world that contains it, in registry order.
+#[derive(Resource)]
+struct MirroredResources<Trait: ?Sized> {
+    resources: Vec<Mirrored>,
+    marker: PhantomData<fn(&Trait)>,
+}
+
+impl<Trait: ?Sized> Default for MirroredResources<Trait> {
+    fn default() -> Self {
+        Self {
+            resources: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Copies the mirrored resources registered as `Trait` from `main` into `sub` and registers them there,
+/// in the same order. Resources that already exist in `sub` are updated in place.
+/// Resources that are disabled, whose run condition is not met or that are unregistered in `main` are
+/// unregistered from `sub`, and removed if the mirror inserted them.
+/// Call it from the extract function of a [`SubApp`], or use [`TraitResourceAppExt::mirror_trait_resources_to`](crate::TraitResourceAppExt::mirror_trait_resources_to).
+pub fn mirror_trait_resources<Trait: ?Sized + TraitResource>(main: &World, sub: &mut World) {
+    let Some(mirror) = main.get_resource::<TraitResourceMirror<Trait>>() else {
+        return;
+    };
+
+    let previous = sub.remove_resource::<MirroredResources<Trait>>().unwrap_or_default().resources;
+    let mut mirrored = Vec::new();
+
+    if let Some(registry) = main.get_resource::<TraitResourceRegistry<Trait>>() {
+        for resource_component_id in registry.active_ids() {
+            let Some(type_id) = main.components().get_info(resource_component_id).and_then(|info| info.type_id()) else {
+                continue;
+            };
+            let Some(entry) = mirror.entries.get(&type_id) else {
+                continue;
+            };
+            let Some(inserted) = (entry.copy)(main, sub) else {
+                continue;
+            };
+
+            let previous = previous.iter().find(|resource| resource.type_id == type_id);
+            mirrored.push(Mirrored {
+                type_id,
+                inserted: inserted || previous.is_some_and(|resource| resource.inserted),
+                registered: previous.is_some_and(|resource| resource.registered),
+            });
+        }
+    }
+
+    for resource in &previous {
+        if mirrored.iter().any(|mirrored| mirrored.type_id == resource.type_id) {
+            continue;
+        }
+
+        let entry = &mirror.entries[&resource.type_id];
+        if resource.registered {
+            (entry.unregister)(sub);
+        }
+        if resource.inserted {
+            (entry.remove)(sub);
+        }
+    }
+
+    // Registrations are only redone from the first resource out of order, so the registry in `sub` is not changed every frame.
+    // Resources that were registered in `sub` by something else than the mirror are left where they are.
+    let registered = previous
+        .iter()
+        .filter(|resource| resource.registered && mirrored.iter().any(|mirrored| mirrored.type_id == resource.type_id))
+        .map(|resource| resource.type_id);
+    let order: Vec<TypeId> = mirrored
+        .iter()
+        .filter(|resource| resource.registered || !registered_in::<Trait>(sub, resource.type_id))
+        .map(|resource| resource.type_id)
+        .collect();
+    let in_order = registered.zip(&order).take_while(|(registered, type_id)| registered == *type_id).count();
+
+    for resource in &mut mirrored {
+        let entry = &mirror.entries[&resource.type_id];
+        if resource.registered && order[in_order..].contains(&resource.type_id) {
+            (entry.unregister)(sub);
+            resource.registered = false;
+        }
+        if !resource.registered {
+            resource.registered = (entry.register)(sub);
+        }
+    }
+
+    sub.insert_resource(MirroredResources::<Trait> {
+        resources: mirrored,
+        marker: PhantomData,
+    });
+}
+
+/// The traits mirrored into the sub-app world that contains it by [`extract_mirrored_trait_resources`].
+#[derive(Resource, Default)]
+pub(crate) struct TraitResourceMirrors {
+    mirrors: HashMap<TypeId, fn(&World, &mut World)>,
+}
+
+impl TraitResourceMirrors {
+    pub(crate) fn add<Trait: ?Sized + TraitResource>(&mut self) {
+        self.mirrors.insert(TypeId::of::<Trait>(), mirror_trait_resources::<Trait>);
+    }
+}
+
+/// Calls [`mirror_trait_resources`] for every trait added to the sub-app with
+/// [`TraitResourceAppExt::mirror_trait_resources_to`](crate::TraitResourceAppExt::mirror_trait_resources_to).
+/// It can be used as the extract function of a [`SubApp`], or called from one.
+pub fn extract_mirrored_trait_resources(main: &mut World, sub_app: &mut App) {
+    let Some(mirrors) = sub_app.world.remove_resource::<TraitResourceMirrors>() else {
+        return;
+    };
+
+    for mirror in mirrors.mirrors.values() {
+        mirror(main, &mut sub_app.world);
+    }
+
+    sub_app.world.insert_resource(mirrors);
+}
+
+pub(crate) fn mirror_trait_resources_to<Trait: ?Sized + TraitResource>(app: &mut App, label: impl AppLabel) {
+    if app.get_sub_app(label.intern()).is_err() {
+        let mut sub_app = App::empty();
+        sub_app.add_schedule(Schedule::new(Main));
+        app.insert_sub_app(label.intern(), SubApp::new(sub_app, extract_mirrored_trait_resources));
+    }
+
+    app.sub_app_mut(label)
+        .world
+        .get_resource_or_insert_with(TraitResourceMirrors::default)
+        .add::<Trait>();
+}
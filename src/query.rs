@@ -0,0 +1,108 @@
+use bevy::{
+    ecs::{
+        component::{ComponentId, ComponentInfo},
+        world::unsafe_world_cell::UnsafeWorldCell,
+    },
+    prelude::*,
+    ptr::Ptr,
+};
+
+use crate::{DynCtor, TraitResource, TraitResourceExt, TraitResourceRef, TraitResourceRegistry};
+
+/// The cast of a resource to one trait of a [`TraitResourceTuple`].
+#[doc(hidden)]
+pub struct TupleCast<Trait: ?Sized>(DynCtor<Trait>);
+
+impl<T: ?Sized> Copy for TupleCast<T> {}
+impl<T: ?Sized> Clone for TupleCast<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Trait: ?Sized> TupleCast<Trait> {
+    pub(crate) fn dyn_ctor(self) -> DynCtor<Trait> {
+        self.0
+    }
+}
+
+/// Traits intersected by [`TraitResourceExt::get_resources_traits`], written as a tuple of references
+/// like `(&dyn A, &dyn B)`, because trait objects can not be elements of a tuple.
+/// Implemented for tuples of two and three traits.
+pub trait TraitResourceTuple {
+    /// The first trait, which [`TraitResourceExt::get_resources_traits_mut`] returns mutably.
+    type First: ?Sized + TraitResource;
+
+    /// The item returned for every resource registered as all traits.
+    type Item<'w>;
+
+    #[doc(hidden)]
+    type Casts: Copy;
+
+    /// The active resources registered as all traits with their casts, in the registry order of the first trait.
+    #[doc(hidden)]
+    fn intersect(world: &World) -> Vec<(ComponentId, Self::Casts)>;
+
+    /// # Safety
+    /// `ptr` must point to the resource of `info`, which the casts were registered for.
+    #[doc(hidden)]
+    unsafe fn cast<'w>(casts: Self::Casts, ptr: Ptr<'w>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self::Item<'w>;
+
+    #[doc(hidden)]
+    fn first(casts: Self::Casts) -> TupleCast<Self::First>;
+
+    #[doc(hidden)]
+    fn update_conditions(world: &mut World);
+}
+
+macro_rules! impl_trait_resource_tuple {
+    ($First:ident $first:ident, $($T:ident $t:ident),+) => {
+        impl<$First: ?Sized + TraitResource, $($T: ?Sized + TraitResource),+> TraitResourceTuple for (&'static $First, $(&'static $T),+) {
+            type First = $First;
+            type Item<'w> = (TraitResourceRef<'w, $First>, $(TraitResourceRef<'w, $T>),+);
+            type Casts = (TupleCast<$First>, $(TupleCast<$T>),+);
+
+            fn intersect(world: &World) -> Vec<(ComponentId, Self::Casts)> {
+                let (Some($first), $(Some($t)),+) = (
+                    world.get_resource::<TraitResourceRegistry<$First>>(),
+                    $(world.get_resource::<TraitResourceRegistry<$T>>()),+
+                ) else {
+                    return vec![];
+                };
+
+                $first
+                    .iter()
+                    .filter(|data| data.is_active())
+                    .filter_map(|data| {
+                        let id = data.resource_component_id;
+                        let casts = (
+                            TupleCast(data.trait_ptr),
+                            $(TupleCast($t.get(id).filter(|data| data.is_active())?.trait_ptr)),+
+                        );
+                        Some((id, casts))
+                    })
+                    .collect()
+            }
+
+            unsafe fn cast<'w>(casts: Self::Casts, ptr: Ptr<'w>, info: &'w ComponentInfo, world: UnsafeWorldCell<'w>) -> Self::Item<'w> {
+                let ($first, $($t),+) = casts;
+                (
+                    TraitResourceRef::from_resource($first.0.cast(ptr), ptr, info, world),
+                    $(TraitResourceRef::from_resource($t.0.cast(ptr), ptr, info, world)),+
+                )
+            }
+
+            fn first(casts: Self::Casts) -> TupleCast<Self::First> {
+                casts.0
+            }
+
+            fn update_conditions(world: &mut World) {
+                world.update_trait_resource_conditions::<$First>();
+                $(world.update_trait_resource_conditions::<$T>();)+
+            }
+        }
+    };
+}
+
+impl_trait_resource_tuple!(A a, B b);
+impl_trait_resource_tuple!(A a, B b, C c);
@@ -12,12 +12,12 @@ pub trait IncrementStringTrait {
     fn increment_string(&mut self);
 }
 
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Debug, Reflect)]
 struct NumberValueResource {
     value: i32,
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Debug)]
 struct NumberValueResource2 {
     string_value: String,
     value: i32,
@@ -62,18 +62,14 @@ impl IncrementStringTrait for NumberValueResource2 {
 }
 
 pub fn increment_value_test_system(world: &mut World) {
-    for res_opt in world.get_resources_trait_mut::<dyn IncrementTrait>() {
-        if let Some(res) = res_opt {
-            res.increment();
-        }
+    for mut res in world.get_resources_trait_mut::<dyn IncrementTrait>().flatten() {
+        res.increment();
     }
 }
 
 pub fn increment_string_value_test_system(world: &mut World) {
-    for res_opt in world.get_resources_trait_mut::<dyn IncrementStringTrait>() {
-        if let Some(res) = res_opt {
-            res.increment_string();
-        }
+    for mut res in world.get_resources_trait_mut::<dyn IncrementStringTrait>().flatten() {
+        res.increment_string();
     }
 }
 
@@ -202,7 +198,7 @@ fn unregister_trait_from_resource() {
 
     match world.get_resource::<TraitResourceRegistry<dyn IncrementTrait>>() {
         Some(registry) => {
-            assert_eq!(registry.trait_data.len(), 2);
+            assert_eq!(registry.len(), 2);
         },
         None => panic!("Registry should exist"),
     }
@@ -211,7 +207,7 @@ fn unregister_trait_from_resource() {
 
     match world.get_resource::<TraitResourceRegistry<dyn IncrementTrait>>() {
         Some(registry) => {
-            assert_eq!(registry.trait_data.len(), 1);
+            assert_eq!(registry.len(), 1);
         },
         None => panic!("Registry should exist"),
     }
@@ -221,3 +217,1328 @@ fn unregister_trait_from_resource() {
     assert!(world.get_resource::<TraitResourceRegistry<dyn IncrementTrait>>().is_none());
     assert!(world.get_resource::<TraitResourceRegistry<dyn IncrementStringTrait>>().is_some());
 }
+
+#[test]
+fn send_sync_trait_objects() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait + Send + Sync, NumberValueResource>();
+    world.insert_resource_as::<dyn IncrementTrait + Send + Sync, NumberValueResource2>(NumberValueResource2 {
+        string_value: "0".to_owned(),
+        value: 3,
+    });
+
+    std::thread::scope(|scope| {
+        for mut res in world.get_resources_trait_mut::<dyn IncrementTrait + Send + Sync>().flatten() {
+            scope.spawn(move || res.increment());
+        }
+    });
+
+    let sum = std::thread::scope(|scope| {
+        world
+            .get_resources_trait::<dyn IncrementTrait + Send + Sync>()
+            .flatten()
+            .map(|res| scope.spawn(move || res.value()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum::<i32>()
+    });
+
+    assert_eq!(sum, 5);
+    assert!(world.get_resource::<TraitResourceRegistry<dyn IncrementTrait>>().is_none());
+}
+
+#[trait_resource]
+pub trait NamedTrait {
+    fn name(&self) -> String;
+}
+
+#[trait_resource(supertraits(NamedTrait))]
+pub trait AnimalTrait: NamedTrait {
+    fn legs(&self) -> u32;
+}
+
+#[trait_resource(supertraits(AnimalTrait))]
+pub trait PetTrait: AnimalTrait {
+    fn owner(&self) -> String;
+}
+
+#[derive(Resource, Default)]
+struct DogResource;
+
+impl NamedTrait for DogResource {
+    fn name(&self) -> String {
+        "Dog".to_owned()
+    }
+}
+
+impl AnimalTrait for DogResource {
+    fn legs(&self) -> u32 {
+        4
+    }
+}
+
+impl PetTrait for DogResource {
+    fn owner(&self) -> String {
+        "Alice".to_owned()
+    }
+}
+
+#[test]
+fn register_resource_as_supertraits() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn PetTrait, DogResource>();
+
+    let names: Vec<String> = world.get_resources_trait::<dyn NamedTrait>().flatten().map(|res| res.name()).collect();
+    assert_eq!(names, vec!["Dog".to_owned()]);
+
+    let legs: Vec<u32> = world.get_resources_trait::<dyn AnimalTrait>().flatten().map(|res| res.legs()).collect();
+    assert_eq!(legs, vec![4]);
+
+    world.unregister_resource_from_trait::<dyn PetTrait, DogResource>();
+
+    assert!(world.get_resource::<TraitResourceRegistry<dyn PetTrait>>().is_none());
+    assert!(world.get_resource::<TraitResourceRegistry<dyn AnimalTrait>>().is_none());
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+}
+
+#[test]
+fn unregister_keeps_explicit_supertrait_registrations() {
+    let mut world = World::new();
+    world.init_resource::<DogResource>();
+    world.register_resource_as::<dyn NamedTrait, DogResource>();
+    world.register_resource_as::<dyn AnimalTrait, DogResource>();
+
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+
+    assert!(world.get_resource::<TraitResourceRegistry<dyn AnimalTrait>>().is_none());
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+
+    // Registered as the supertrait both directly and through a subtrait.
+    world.register_resource_as::<dyn PetTrait, DogResource>();
+    world.register_resource_as::<dyn AnimalTrait, DogResource>();
+
+    world.unregister_resource_from_trait::<dyn PetTrait, DogResource>();
+    assert_eq!(world.get_resources_trait::<dyn AnimalTrait>().flatten().count(), 1);
+
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+    assert!(world.get_resource::<TraitResourceRegistry<dyn AnimalTrait>>().is_none());
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+}
+
+#[test]
+fn unregister_keeps_implied_registrations() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn AnimalTrait, DogResource>();
+    world.register_resource_as::<dyn NamedTrait, DogResource>();
+
+    world.unregister_resource_from_trait::<dyn NamedTrait, DogResource>();
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+
+    // The registration as the supertrait is now only implied by the subtrait.
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+}
+
+#[test]
+fn conditional_registrations_skip_supertraits() {
+    let mut world = World::new();
+    world.init_resource::<DogResource>();
+    world.register_resource_as::<dyn NamedTrait, DogResource>();
+    world.register_resource_as_if::<dyn AnimalTrait, DogResource, _>(|| true);
+    world.update_trait_resource_conditions::<dyn AnimalTrait>();
+
+    assert_eq!(world.get_resources_trait::<dyn AnimalTrait>().flatten().count(), 1);
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+
+    // The direct registration as the supertrait is kept.
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+
+    world.unregister_resource_from_trait::<dyn NamedTrait, DogResource>();
+    world.register_resource_as_if::<dyn AnimalTrait, DogResource, _>(|| true);
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+}
+
+#[test]
+fn register_after_conditional_registration_adds_supertraits() {
+    let mut world = World::new();
+    world.init_resource::<DogResource>();
+    world.register_resource_as_if::<dyn AnimalTrait, DogResource, _>(|| true);
+    world.register_resource_as::<dyn AnimalTrait, DogResource>();
+
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+}
+
+#[test]
+fn register_resource_as_send_sync_supertraits() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn AnimalTrait + Send + Sync, DogResource>();
+
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait + Send + Sync>().flatten().count(), 1);
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+}
+
+#[test]
+fn intersect_trait_registries() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+
+    for mut res in world.get_resources_traits_mut::<(&dyn IncrementTrait, &dyn IncrementStringTrait)>().flatten() {
+        res.increment();
+    }
+
+    let values: Vec<(i32, String)> = world
+        .get_resources_traits::<(&dyn IncrementTrait, &dyn IncrementStringTrait)>()
+        .flatten()
+        .map(|(a, b)| (a.value(), b.string_value()))
+        .collect();
+
+    assert_eq!(values, vec![(1, "0".to_owned())]);
+    assert_eq!(world.resource::<NumberValueResource>().value(), 0);
+
+    let (_, string_value) = world
+        .get_resources_traits::<(&dyn IncrementTrait, &dyn IncrementStringTrait)>()
+        .flatten()
+        .next()
+        .unwrap();
+    assert_eq!(string_value.downcast_ref::<NumberValueResource2>().unwrap().value, 1);
+}
+
+#[test]
+fn intersect_three_trait_registries() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.register_resource_as::<dyn std::fmt::Debug, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+
+    type Query = (&'static dyn IncrementTrait, &'static dyn IncrementStringTrait, &'static dyn std::fmt::Debug);
+    assert_eq!(world.get_resources_traits::<Query>().count(), 0);
+
+    world.register_resource_as::<dyn std::fmt::Debug, NumberValueResource2>();
+
+    let values: Vec<(i32, String)> = world
+        .get_resources_traits::<(&dyn IncrementTrait, &dyn IncrementStringTrait, &dyn std::fmt::Debug)>()
+        .flatten()
+        .map(|(a, b, _)| (a.value(), b.string_value()))
+        .collect();
+    assert_eq!(values, vec![(0, "0".to_owned())]);
+}
+
+#[test]
+fn intersect_missing_trait_registry() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+
+    assert_eq!(world.get_resources_traits::<(&dyn IncrementTrait, &dyn IncrementStringTrait)>().count(), 0);
+    assert_eq!(world.get_resources_traits_mut::<(&dyn IncrementTrait, &dyn IncrementStringTrait)>().count(), 0);
+}
+
+#[test]
+fn disjoint_trait_registries_mut() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+
+    let (values, strings) = world
+        .get_resources_trait_pair_mut::<dyn IncrementTrait, dyn IncrementStringTrait>()
+        .unwrap();
+
+    for (mut value, mut string) in values.flatten().zip(strings.flatten()) {
+        value.increment();
+        string.increment_string();
+    }
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().string_value(), "1");
+}
+
+#[test]
+fn overlapping_trait_registries_mut() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+
+    let component_id = world.components().resource_id::<NumberValueResource2>().unwrap();
+
+    match world.get_resources_trait_pair_mut::<dyn IncrementTrait, dyn IncrementStringTrait>() {
+        Err(TraitResourceError::OverlappingResource { component_id: overlap, name }) => {
+            assert_eq!(overlap, component_id);
+            assert!(name.ends_with("NumberValueResource2"));
+        }
+        _ => panic!("Registries should overlap"),
+    }
+}
+
+#[derive(Resource, Default)]
+struct IncrementLog(Vec<i32>);
+
+#[test]
+fn trait_resource_scope() {
+    let mut world = World::new();
+    world.init_resource::<IncrementLog>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+
+    let value = world.trait_resource_scope::<dyn IncrementTrait, _>(|world, res| {
+        res.increment();
+        world.resource_mut::<IncrementLog>().0.push(res.value());
+
+        // The scoped resource is not in the world while the scope runs.
+        assert!(world.get_resource::<NumberValueResource>().is_none());
+        assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().flatten().count(), 1);
+
+        res.value()
+    });
+
+    assert_eq!(value, Some(1));
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<IncrementLog>().0, vec![1]);
+}
+
+#[test]
+fn trait_resource_scope_without_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.remove_resource::<NumberValueResource>();
+
+    assert!(world.trait_resource_scope::<dyn IncrementTrait, _>(|_, _| ()).is_none());
+}
+
+#[test]
+fn trait_resource_scope_keeps_added_tick() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.clear_trackers();
+
+    world.trait_resource_scope::<dyn IncrementTrait, _>(|_, res| res.increment());
+
+    assert!(!world.is_resource_added::<NumberValueResource>());
+    assert!(world.is_resource_changed::<NumberValueResource>());
+}
+
+#[test]
+#[should_panic(expected = "was inserted during a call to World::resource_scope")]
+fn trait_resource_scope_panics_on_insert() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+
+    world.trait_resource_scope::<dyn IncrementTrait, _>(|world, _| world.init_resource::<NumberValueResource>());
+}
+
+#[test]
+fn for_each_trait_resource_scoped() {
+    let mut world = World::new();
+    world.init_resource::<IncrementLog>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.insert_resource_as::<dyn IncrementTrait, NumberValueResource2>(NumberValueResource2 {
+        string_value: "0".to_owned(),
+        value: 3,
+    });
+
+    world.for_each_trait_resource_scoped::<dyn IncrementTrait>(|world, res| {
+        res.increment();
+        world.resource_mut::<IncrementLog>().0.push(res.value());
+    });
+
+    assert_eq!(world.resource::<IncrementLog>().0, vec![1, 4]);
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 4);
+
+    let values: Vec<i32> = world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1, 4]);
+}
+
+#[trait_resource]
+pub trait OutputTrait {
+    type Output: Clone;
+    fn output(&self) -> Self::Output;
+}
+
+#[derive(Resource, Default)]
+struct FloatOutputResource;
+
+#[derive(Resource, Default)]
+struct StringOutputResource;
+
+impl OutputTrait for FloatOutputResource {
+    type Output = f32;
+    fn output(&self) -> f32 {
+        1.5
+    }
+}
+
+impl OutputTrait for StringOutputResource {
+    type Output = String;
+    fn output(&self) -> String {
+        "output".to_owned()
+    }
+}
+
+#[test]
+fn trait_with_associated_type() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn OutputTrait<Output = f32>, FloatOutputResource>();
+    world.init_resource_as::<dyn OutputTrait<Output = String> + Send + Sync, StringOutputResource>();
+
+    let floats: Vec<f32> = world.get_resources_trait::<dyn OutputTrait<Output = f32>>().flatten().map(|res| res.output()).collect();
+    assert_eq!(floats, vec![1.5]);
+
+    let strings: Vec<String> = world
+        .get_resources_trait::<dyn OutputTrait<Output = String> + Send + Sync>()
+        .flatten()
+        .map(|res| res.output())
+        .collect();
+    assert_eq!(strings, vec!["output".to_owned()]);
+}
+
+#[test]
+fn foreign_trait_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn std::fmt::Debug, NumberValueResource>();
+    world.register_resource_as::<dyn Reflect, NumberValueResource>();
+
+    let debug: Vec<String> = world.get_resources_trait::<dyn std::fmt::Debug>().flatten().map(|res| format!("{res:?}")).collect();
+    assert_eq!(debug, vec!["NumberValueResource { value: 0 }".to_owned()]);
+
+    let type_paths: Vec<&str> = world.get_resources_trait::<dyn Reflect>().flatten().map(|res| res.into_inner().reflect_short_type_path()).collect();
+    assert_eq!(type_paths, vec!["NumberValueResource"]);
+}
+
+pub trait ExistingTrait<T> {
+    fn existing(&self) -> T;
+}
+
+impl_trait_resource!(<T: 'static> dyn ExistingTrait<T>);
+
+impl ExistingTrait<i32> for NumberValueResource {
+    fn existing(&self) -> i32 {
+        self.value
+    }
+}
+
+#[test]
+fn existing_trait_resource() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn ExistingTrait<i32>, NumberValueResource>();
+
+    let values: Vec<i32> = world.get_resources_trait::<dyn ExistingTrait<i32>>().flatten().map(|res| res.existing()).collect();
+    assert_eq!(values, vec![0]);
+}
+
+#[derive(Resource, Default, TraitResources)]
+#[trait_resources(dyn IncrementTrait, dyn IncrementStringTrait)]
+struct DerivedTraitsResource {
+    inner: NumberValueResource2,
+}
+
+impl IncrementTrait for DerivedTraitsResource {
+    fn value(&self) -> i32 {
+        self.inner.value()
+    }
+    fn increment(&mut self) {
+        self.inner.increment();
+    }
+}
+
+impl IncrementStringTrait for DerivedTraitsResource {
+    fn string_value(&self) -> String {
+        self.inner.string_value()
+    }
+    fn increment_string(&mut self) {
+        self.inner.increment_string();
+    }
+}
+
+#[test]
+fn init_trait_resources() {
+    let mut app = App::new();
+    app.init_trait_resources::<DerivedTraitsResource>();
+    app.add_systems(Update, (increment_value_test_system, increment_string_value_test_system));
+
+    app.update();
+
+    assert_eq!(app.world.resource::<DerivedTraitsResource>().value(), 1);
+    assert_eq!(app.world.resource::<DerivedTraitsResource>().string_value(), "1");
+}
+
+#[test]
+fn insert_trait_resources() {
+    let mut world = World::new();
+    world.insert_trait_resources(DerivedTraitsResource {
+        inner: NumberValueResource2 {
+            string_value: "4".to_owned(),
+            value: 2,
+        },
+    });
+
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).sum::<i32>(), 2);
+    assert_eq!(world.get_resources_trait::<dyn IncrementStringTrait>().flatten().map(|res| res.string_value()).collect::<String>(), "4");
+}
+
+#[derive(Resource)]
+struct FromWorldResource {
+    value: i32,
+}
+
+impl FromWorld for FromWorldResource {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            value: world.resource::<NumberValueResource>().value() * 10,
+        }
+    }
+}
+
+impl IncrementTrait for FromWorldResource {
+    fn value(&self) -> i32 {
+        self.value
+    }
+    fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+#[test]
+fn init_resource_as_from_world() {
+    let mut world = World::new();
+    world.insert_resource(NumberValueResource { value: 2 });
+    world.init_resource_as::<dyn IncrementTrait, FromWorldResource>();
+
+    assert_eq!(world.resource::<FromWorldResource>().value(), 20);
+}
+
+#[test]
+fn init_resource_as_keeps_existing_resource() {
+    let mut world = World::new();
+    world.insert_resource(NumberValueResource { value: 2 });
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 2);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().flatten().count(), 1);
+}
+
+#[test]
+fn replace_resource_as_overrides_existing_resource() {
+    let mut world = World::new();
+    world.insert_resource(NumberValueResource { value: 2 });
+    world.replace_resource_as::<dyn IncrementTrait, NumberValueResource>();
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 0);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().flatten().count(), 1);
+}
+
+struct NonSendValue {
+    value: i32,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl IncrementTrait for NonSendValue {
+    fn value(&self) -> i32 {
+        self.value
+    }
+    fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+#[test]
+fn non_send_resources_as_trait() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.insert_non_send_resource_as::<dyn IncrementTrait, NonSendValue>(NonSendValue {
+        value: 5,
+        _not_send: std::marker::PhantomData,
+    });
+
+    for mut res in world.get_non_send_resources_trait_mut::<dyn IncrementTrait>().flatten() {
+        res.increment();
+    }
+
+    let values: Vec<i32> = world.get_non_send_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![6]);
+    let res = world.get_non_send_resources_trait::<dyn IncrementTrait>().flatten().next().unwrap();
+    assert_eq!(res.downcast_ref::<NonSendValue>().unwrap().value, 6);
+
+    // The non-send registry is separate from the send one.
+    let values: Vec<i32> = world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![0]);
+
+    world.unregister_non_send_from_trait::<dyn IncrementTrait, NonSendValue>();
+    assert_eq!(world.get_non_send_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert!(world.get_non_send_resource::<NonSendValue>().is_some());
+}
+
+#[test]
+#[should_panic(expected = "Trying to register a nonexistent non-send resource")]
+fn registering_non_existing_non_send_resource_should_panic() {
+    let mut world = World::new();
+    world.register_non_send_as::<dyn IncrementTrait, NonSendValue>();
+}
+
+#[derive(Event)]
+struct ScoreEvent(i32);
+
+#[derive(Resource, Default)]
+struct ScoreListener {
+    total: i32,
+}
+
+#[derive(Resource, Default)]
+struct ScoreLog(Vec<i32>);
+
+impl EventListener<ScoreEvent> for ScoreListener {
+    fn on_event(&mut self, event: &ScoreEvent) {
+        self.total += event.0;
+    }
+}
+
+impl EventListener<ScoreEvent> for ScoreLog {
+    fn on_event(&mut self, event: &ScoreEvent) {
+        self.0.push(event.0);
+    }
+}
+
+#[test]
+fn dispatch_events_to_trait_resources() {
+    let mut app = App::new();
+    app.add_trait_event_dispatch::<ScoreEvent>()
+        .add_trait_event_dispatch::<ScoreEvent>()
+        .init_resource_as::<dyn EventListener<ScoreEvent>, ScoreListener>()
+        .init_resource_as::<dyn EventListener<ScoreEvent>, ScoreLog>();
+
+    app.world.send_event(ScoreEvent(2));
+    app.world.send_event(ScoreEvent(3));
+    app.update();
+
+    app.world.send_event(ScoreEvent(4));
+    app.update();
+    app.update();
+
+    assert_eq!(app.world.resource::<ScoreListener>().total, 9);
+    assert_eq!(app.world.resource::<ScoreLog>().0, vec![2, 3, 4]);
+}
+
+#[trait_resource]
+pub trait ConvertTrait<T, const N: usize = 1> {
+    fn convert(&self) -> [T; N];
+}
+
+impl ConvertTrait<String> for NumberValueResource {
+    fn convert(&self) -> [String; 1] {
+        [self.value.to_string()]
+    }
+}
+
+#[test]
+fn generic_trait_resource() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn ConvertTrait<String>, NumberValueResource>();
+
+    let values: Vec<[String; 1]> = world.get_resources_trait::<dyn ConvertTrait<String>>().flatten().map(|res| res.convert()).collect();
+    assert_eq!(values, vec![["0".to_owned()]]);
+}
+
+#[test]
+fn disabled_trait_resources_are_skipped() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource>(false);
+
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems(increment_value_test_system);
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 0);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 1);
+
+    let states: Vec<(i32, bool)> = world
+        .get_all_resources_trait::<dyn IncrementTrait>()
+        .map(|(res, enabled)| (res.unwrap().value(), enabled))
+        .collect();
+    assert_eq!(states, vec![(0, false), (1, true)]);
+
+    // Re-registering keeps the state.
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert_eq!(world.get_resources_traits::<(&dyn IncrementStringTrait, &dyn IncrementTrait)>().count(), 0);
+    assert_eq!(world.get_resources_trait::<dyn IncrementStringTrait>().count(), 1);
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource>(true);
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 1);
+    assert_eq!(world.get_all_resources_trait::<dyn IncrementTrait>().count(), 2);
+}
+
+#[derive(Resource)]
+struct IncrementFlag;
+
+#[test]
+fn conditional_trait_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource::<NumberValueResource2>();
+    world.register_resource_as_if::<dyn IncrementTrait, NumberValueResource2, _>(resource_exists::<IncrementFlag>());
+
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems(increment_value_test_system);
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 0);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().count(), 1);
+
+    world.insert_resource(IncrementFlag);
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 2);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 1);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().count(), 2);
+
+    // Re-registering keeps the condition.
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.remove_resource::<IncrementFlag>();
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 3);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 1);
+
+    // Unregistering drops the condition.
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 2);
+}
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum TestState {
+    #[default]
+    Menu,
+    InGame,
+}
+
+#[test]
+fn state_scoped_trait_resources() {
+    let mut app = App::new();
+    app.add_state::<TestState>()
+        .init_resource::<NumberValueResource>()
+        .register_resource_as_in_state::<dyn IncrementTrait, NumberValueResource, _>(TestState::InGame)
+        .init_resource_as_in_state::<dyn IncrementTrait, NumberValueResource2, _>(TestState::InGame);
+
+    app.update();
+    assert_eq!(app.world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert!(!app.world.contains_resource::<NumberValueResource2>());
+
+    app.world.resource_mut::<NextState<TestState>>().set(TestState::InGame);
+    app.update();
+    assert_eq!(app.world.get_resources_trait::<dyn IncrementTrait>().count(), 2);
+
+    app.world.resource_mut::<NextState<TestState>>().set(TestState::Menu);
+    app.update();
+    assert_eq!(app.world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert!(app.world.contains_resource::<NumberValueResource>());
+    assert!(!app.world.contains_resource::<NumberValueResource2>());
+}
+
+#[test]
+fn conditional_registration_before_state() {
+    let mut app = App::new();
+    app.init_resource::<NumberValueResource>()
+        .register_resource_as_if::<dyn IncrementTrait, NumberValueResource, _>(in_state(TestState::Menu));
+
+    // The condition has not run yet.
+    assert_eq!(app.world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+
+    app.add_state::<TestState>();
+    app.update();
+    assert_eq!(app.world.get_resources_trait_mut::<dyn IncrementTrait>().count(), 1);
+}
+
+#[derive(Resource, Default)]
+struct ConditionRuns(usize);
+
+/// Returns a function that runs a system gated by `condition` and returns whether it ran.
+fn gated_system<M>(condition: impl Condition<M>) -> impl FnMut(&mut World) -> bool {
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems((|mut runs: ResMut<ConditionRuns>| runs.0 += 1).run_if(condition));
+
+    move |world: &mut World| {
+        world.init_resource::<ConditionRuns>();
+        schedule.run(world);
+        std::mem::take(&mut world.resource_mut::<ConditionRuns>().0) > 0
+    }
+}
+
+#[test]
+fn any_trait_resource_exists_condition() {
+    let mut world = World::new();
+    world.init_resource::<NumberValueResource>();
+    let mut run = gated_system(any_trait_resource_exists::<dyn IncrementTrait>());
+
+    assert!(!run(&mut world));
+
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    assert!(run(&mut world));
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource>(false);
+    assert!(!run(&mut world));
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource>(true);
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource>();
+    assert!(!run(&mut world));
+}
+
+#[test]
+fn trait_resource_count_at_least_condition() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    let mut run = gated_system(trait_resource_count_at_least::<dyn IncrementTrait>(2));
+
+    assert!(!run(&mut world));
+
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    assert!(run(&mut world));
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+    assert!(!run(&mut world));
+}
+
+#[test]
+fn any_trait_resource_changed_condition() {
+    let mut world = World::new();
+    world.init_resource::<NumberValueResource>();
+    world.init_resource::<NumberValueResource2>();
+    let mut run = gated_system(any_trait_resource_changed::<dyn IncrementTrait>());
+
+    world.resource_mut::<NumberValueResource>().increment();
+    assert!(!run(&mut world));
+
+    // Registering does not change the resource.
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    assert!(!run(&mut world));
+
+    world.resource_mut::<NumberValueResource>().increment();
+    assert!(run(&mut world));
+    assert!(!run(&mut world));
+
+    // Disabled resources are ignored.
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+    world.resource_mut::<NumberValueResource2>().increment();
+    assert!(!run(&mut world));
+}
+
+#[test]
+fn trait_registry_changed_condition() {
+    let mut world = World::new();
+    world.init_resource::<NumberValueResource>();
+    let mut run = gated_system(trait_registry_changed::<dyn IncrementTrait>());
+
+    assert!(!run(&mut world));
+
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    assert!(run(&mut world));
+    assert!(!run(&mut world));
+
+    world.resource_mut::<NumberValueResource>().increment();
+    assert!(!run(&mut world));
+
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource>(false);
+    assert!(run(&mut world));
+
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource>();
+    assert!(run(&mut world));
+    assert!(!run(&mut world));
+}
+
+#[test]
+fn mirror_trait_resources_into_sub_app() {
+    let mut app = App::new();
+    app.init_resource_as::<dyn IncrementTrait, NumberValueResource2>()
+        .init_resource_as::<dyn IncrementTrait, NumberValueResource>()
+        .mirror_resource_as::<dyn IncrementTrait, NumberValueResource2>()
+        .mirror_reflect_resource_as::<dyn IncrementTrait, NumberValueResource>();
+
+    app.world.resource_mut::<NumberValueResource>().increment();
+
+    let mut sub_app = App::empty();
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    let values: Vec<i32> = sub_app.world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![0, 1]);
+
+    // Changes in the sub-app are overwritten by the next extraction, in place.
+    for mut res in sub_app.world.get_resources_trait_mut::<dyn IncrementTrait>().flatten() {
+        res.increment();
+    }
+    sub_app.world.clear_trackers();
+    app.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    let values: Vec<i32> = sub_app.world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1]);
+    assert!(!sub_app.world.is_resource_added::<NumberValueResource>());
+    assert!(!sub_app.world.contains_resource::<NumberValueResource2>());
+
+    app.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource>();
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    assert_eq!(sub_app.world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert!(!sub_app.world.contains_resource::<NumberValueResource>());
+}
+
+#[test]
+fn mirror_keeps_sub_app_resources() {
+    let mut app = App::new();
+    app.init_resource_as::<dyn IncrementTrait, NumberValueResource2>()
+        .mirror_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+
+    let mut sub_app = App::empty();
+    sub_app.insert_resource(NumberValueResource2 { value: 5, ..default() });
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    assert_eq!(sub_app.world.resource::<NumberValueResource2>().value, 0);
+    assert_eq!(sub_app.world.get_resources_trait::<dyn IncrementTrait>().count(), 1);
+
+    // The resource belongs to the sub-app, so it is only unregistered.
+    app.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource2>();
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    assert_eq!(sub_app.world.get_resources_trait::<dyn IncrementTrait>().count(), 0);
+    assert!(sub_app.world.contains_resource::<NumberValueResource2>());
+}
+
+#[derive(Resource, Clone, Default)]
+struct MirroredValue(i32);
+
+impl IncrementTrait for MirroredValue {
+    fn value(&self) -> i32 {
+        self.0
+    }
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+}
+
+#[test]
+fn mirror_keeps_order_when_enabling_a_resource() {
+    let mut app = App::new();
+    app.insert_resource_as::<dyn IncrementTrait, _>(NumberValueResource { value: 1 })
+        .insert_resource_as::<dyn IncrementTrait, _>(NumberValueResource2 { value: 2, ..default() })
+        .insert_resource_as::<dyn IncrementTrait, _>(MirroredValue(3))
+        .mirror_reflect_resource_as::<dyn IncrementTrait, NumberValueResource>()
+        .mirror_resource_as::<dyn IncrementTrait, NumberValueResource2>()
+        .mirror_resource_as::<dyn IncrementTrait, MirroredValue>()
+        .set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+
+    let mut sub_app = App::empty();
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    let values: Vec<i32> = sub_app.world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1, 3]);
+
+    app.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(true);
+    mirror_trait_resources::<dyn IncrementTrait>(&app.world, &mut sub_app.world);
+
+    let values: Vec<i32> = sub_app.world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct MirrorApp;
+
+#[test]
+fn mirror_trait_resources_to_sub_app() {
+    let mut app = App::new();
+    app.init_resource_as::<dyn IncrementTrait, NumberValueResource>()
+        .mirror_reflect_resource_as::<dyn IncrementTrait, NumberValueResource>()
+        .mirror_trait_resources_to::<dyn IncrementTrait>(MirrorApp);
+
+    app.world.resource_mut::<NumberValueResource>().increment();
+    app.update();
+
+    let values: Vec<i32> = app
+        .sub_app(MirrorApp)
+        .world
+        .get_resources_trait::<dyn IncrementTrait>()
+        .flatten()
+        .map(|res| res.value())
+        .collect();
+    assert_eq!(values, vec![1]);
+}
+
+#[derive(Component)]
+struct Target(TraitResourceHandle<dyn IncrementTrait>);
+
+#[test]
+fn trait_resource_handles() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+
+    let handles = world.trait_resource_handles::<dyn IncrementTrait>();
+    assert_eq!(handles.len(), 2);
+
+    let handle = world.trait_resource_handle::<dyn IncrementTrait, NumberValueResource2>().unwrap();
+    assert_eq!(handles[1], handle);
+
+    let entity = world.spawn(Target(handle)).id();
+    let handle = world.get::<Target>(entity).unwrap().0;
+    world.get_mut_by_handle(&handle).unwrap().increment();
+
+    assert_eq!(world.get_by_handle(&handle).unwrap().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource>().value(), 0);
+
+    // Disabled resources can still be resolved.
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+    assert!(world.get_by_handle(&handle).is_some());
+    assert_eq!(world.trait_resource_handles::<dyn IncrementTrait>().len(), 1);
+
+    // Handles from another world are rejected.
+    let mut other_world = World::new();
+    other_world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    assert!(other_world.get_by_handle(&handle).is_none());
+
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource2>();
+    assert!(world.get_by_handle(&handle).is_none());
+
+    world.register_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.remove_resource::<NumberValueResource2>();
+    assert!(world.get_mut_by_handle(&handle).is_none());
+}
+
+#[test]
+fn trait_resource_item_handles() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource2::default());
+
+    let handle = world.trait_resource_handle::<dyn IncrementTrait, NumberValueResource>().unwrap();
+    let handles: Vec<_> = world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.handle()).collect();
+    assert_eq!(handles, vec![Some(handle), None]);
+
+    let handles: Vec<_> = world.get_resources_trait_mut::<dyn IncrementTrait>().flatten().map(|res| res.handle()).collect();
+    assert_eq!(handles, vec![Some(handle), None]);
+}
+
+#[test]
+fn take_and_restore_trait_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.set_trait_resource_enabled::<dyn IncrementTrait, NumberValueResource2>(false);
+
+    let mut taken = world.take_resources_trait::<dyn IncrementTrait>();
+    assert_eq!(taken.len(), 2);
+    assert!(!world.contains_resource::<NumberValueResource>());
+    assert!(!world.contains_resource::<NumberValueResource2>());
+    assert_eq!(world.get_all_resources_trait::<dyn IncrementTrait>().filter(|(res, _)| res.is_some()).count(), 0);
+
+    for resource in &mut taken {
+        resource.increment();
+    }
+
+    // Unregistered resources are registered again in the order they are restored.
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource>();
+    taken.reverse();
+    world.restore_resources_trait(taken);
+
+    let states: Vec<(i32, bool)> = world
+        .get_all_resources_trait::<dyn IncrementTrait>()
+        .map(|(res, enabled)| (res.unwrap().value(), enabled))
+        .collect();
+    assert_eq!(states, vec![(1, false), (1, true)]);
+    assert_eq!(world.resource::<NumberValueResource2>().string_value(), "0");
+}
+
+#[test]
+fn restore_registers_supertraits_again() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn AnimalTrait, DogResource>();
+
+    let taken = world.take_resources_trait::<dyn AnimalTrait>();
+    world.unregister_resource_from_trait::<dyn AnimalTrait, DogResource>();
+    assert!(world.get_resource::<TraitResourceRegistry<dyn NamedTrait>>().is_none());
+
+    world.restore_resources_trait(taken);
+    assert_eq!(world.get_resources_trait::<dyn AnimalTrait>().flatten().count(), 1);
+    assert_eq!(world.get_resources_trait::<dyn NamedTrait>().flatten().count(), 1);
+}
+
+#[derive(Resource)]
+struct DropCounter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl IncrementTrait for DropCounter {
+    fn value(&self) -> i32 {
+        self.0.load(std::sync::atomic::Ordering::SeqCst) as i32
+    }
+
+    fn increment(&mut self) {}
+}
+
+#[test]
+fn taken_trait_resources_are_dropped() {
+    let drops = std::sync::Arc::default();
+    let mut world = World::new();
+    world.insert_resource_as::<dyn IncrementTrait, _>(DropCounter(std::sync::Arc::clone(&drops)));
+
+    let taken = world.take_resources_trait::<dyn IncrementTrait>();
+    assert_eq!(taken[0].value(), 0);
+    assert_eq!(taken[0].handle(), world.trait_resource_handle::<dyn IncrementTrait, DropCounter>().unwrap());
+
+    drop(taken);
+    assert_eq!(drops.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn trait_collection_entries_are_iterated() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource { value: 10 });
+    let id = world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource { value: 20 });
+    world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource { value: 30 });
+
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems(increment_value_test_system);
+    schedule.run(&mut world);
+
+    let values: Vec<i32> = world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1, 11, 21, 31]);
+
+    let mut collection = world.resource_mut::<TraitCollection<dyn IncrementTrait>>();
+    assert_eq!(collection.remove(id).unwrap().value(), 21);
+    assert_eq!(collection.len(), 2);
+
+    let values: Vec<i32> = world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect();
+    assert_eq!(values, vec![1, 11, 31]);
+
+    // A collection can not be borrowed twice.
+    assert!(world.get_resources_trait_pair_mut::<dyn IncrementTrait, dyn IncrementTrait>().is_err());
+    world.unregister_resource_from_trait::<dyn IncrementTrait, NumberValueResource2>();
+    assert!(world.get_resources_trait_pair_mut::<dyn IncrementTrait, dyn IncrementTrait>().is_err());
+}
+
+#[test]
+fn trait_resource_items_expose_identity() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource { value: 10 });
+
+    let items: Vec<TraitResourceRef<dyn IncrementTrait>> = world.get_resources_trait::<dyn IncrementTrait>().flatten().collect();
+    assert_eq!(items[0].component_id(), world.components().resource_id::<NumberValueResource>().unwrap());
+    assert_eq!(items[1].type_id(), TypeId::of::<NumberValueResource2>());
+    assert!(items[1].type_name().ends_with("NumberValueResource2"));
+    assert_eq!(items[1].downcast_ref::<NumberValueResource2>().unwrap().string_value(), "0");
+    assert!(items[0].downcast_ref::<NumberValueResource2>().is_none());
+    assert_eq!(items[2].component_id(), world.components().resource_id::<TraitCollection<dyn IncrementTrait>>().unwrap());
+    assert_eq!(items[2].downcast_ref::<NumberValueResource>().unwrap().value, 10);
+
+    for mut res in world.get_resources_trait_mut::<dyn IncrementTrait>().flatten() {
+        if let Some(resource) = res.downcast_mut::<NumberValueResource2>() {
+            resource.increment_string();
+        } else {
+            res.increment();
+        }
+    }
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 0);
+    assert_eq!(world.resource::<NumberValueResource2>().string_value(), "1");
+}
+
+#[test]
+fn cross_cast_trait_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource2>();
+    world.register_resource_as::<dyn IncrementStringTrait, NumberValueResource2>();
+    world.add_to_trait_collection::<dyn IncrementTrait, _>(NumberValueResource2::default());
+
+    let strings: Vec<Option<String>> = world
+        .get_resources_trait::<dyn IncrementTrait>()
+        .flatten()
+        .map(|res| cross_cast::<dyn IncrementTrait, dyn IncrementStringTrait>(&res).map(|res| res.string_value()))
+        .collect();
+    assert_eq!(strings, vec![None, Some("0".to_owned()), None]);
+
+    for mut res in world.get_resources_trait_mut::<dyn IncrementTrait>().flatten() {
+        if let Some(string) = cross_cast_mut::<dyn IncrementTrait, dyn IncrementStringTrait>(&mut res) {
+            string.increment_string();
+        }
+        res.increment();
+    }
+
+    assert_eq!(world.resource::<NumberValueResource>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().value(), 1);
+    assert_eq!(world.resource::<NumberValueResource2>().string_value(), "1");
+}
+
+struct Counter {
+    value: i32,
+}
+
+impl IncrementTrait for Counter {
+    fn value(&self) -> i32 {
+        self.value
+    }
+
+    fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+#[derive(Resource)]
+struct CounterSettings {
+    name: &'static str,
+    counter: Counter,
+}
+
+#[test]
+fn projected_trait_resources() {
+    let mut world = World::new();
+    world.init_resource_as::<dyn IncrementTrait, NumberValueResource>();
+    world.insert_resource(CounterSettings { name: "counter", counter: Counter { value: 10 } });
+    world.register_resource_as_with::<dyn IncrementTrait, CounterSettings>(|settings| &settings.counter, |settings| &mut settings.counter);
+
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems(increment_value_test_system);
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<CounterSettings>().counter.value, 11);
+
+    let settings = world
+        .get_resources_trait::<dyn IncrementTrait>()
+        .flatten()
+        .find_map(|res| res.downcast_ref::<CounterSettings>())
+        .unwrap();
+    assert_eq!(settings.name, "counter");
+
+    world.for_each_trait_resource_scoped::<dyn IncrementTrait>(|_, res| res.increment());
+    assert_eq!(world.resource::<CounterSettings>().counter.value, 12);
+
+    // Projected resources are taken as a whole and keep their projection.
+    let mut taken = world.take_resources_trait::<dyn IncrementTrait>();
+    assert_eq!(taken.len(), 2);
+    assert!(!world.contains_resource::<CounterSettings>());
+    taken[1].increment();
+    world.restore_resources_trait(taken);
+    assert_eq!(world.resource::<CounterSettings>().counter.value, 13);
+}
+
+#[test]
+fn register_dynamic_trait_resources() {
+    use bevy::{
+        ecs::component::{ComponentDescriptor, StorageType},
+        ptr::OwningPtr,
+    };
+    use std::alloc::Layout;
+
+    let mut world = World::new();
+
+    let typed_id = world.init_component_with_descriptor(ComponentDescriptor::new_resource::<NumberValueResource>());
+    OwningPtr::make(NumberValueResource { value: 5 }, |ptr| unsafe { world.insert_resource_by_id(typed_id, ptr) });
+
+    // SAFETY: Counter has no drop glue.
+    let untyped_id = world.init_component_with_descriptor(unsafe {
+        ComponentDescriptor::new_with_layout("Scripted", StorageType::Table, Layout::new::<Counter>(), None)
+    });
+    OwningPtr::make(Counter { value: 7 }, |ptr| unsafe { world.insert_resource_by_id(untyped_id, ptr) });
+
+    assert_eq!(
+        world.try_register_resource_by_id_as::<dyn IncrementTrait, NumberValueResource2>(typed_id).err(),
+        Some(TraitResourceError::MismatchedType {
+            component_id: typed_id,
+            expected: std::any::type_name::<NumberValueResource2>(),
+            found: std::any::type_name::<NumberValueResource>().to_owned(),
+        })
+    );
+    assert!(world.try_register_resource_by_id_as::<dyn IncrementTrait, NumberValueResource>(untyped_id).is_err());
+
+    world.try_register_resource_by_id_as::<dyn IncrementTrait, NumberValueResource>(typed_id).unwrap();
+    unsafe { world.register_resource_by_id_as::<dyn IncrementTrait>(untyped_id, |ptr| ptr.cast::<Counter>() as *mut dyn IncrementTrait) };
+
+    let mut schedule = Schedule::new(Update);
+    schedule.add_systems(increment_value_test_system);
+    schedule.run(&mut world);
+
+    let items: Vec<(i32, bool)> = world
+        .get_resources_trait::<dyn IncrementTrait>()
+        .flatten()
+        .map(|res| (res.value(), res.try_type_id().is_some()))
+        .collect();
+    assert_eq!(items, vec![(6, true), (8, false)]);
+
+    // Dynamic resources can not be moved out of the world.
+    assert!(world.take_resources_trait::<dyn IncrementTrait>().is_empty());
+
+    world.unregister_resource_by_id_from_trait::<dyn IncrementTrait>(untyped_id);
+    assert_eq!(world.get_resources_trait::<dyn IncrementTrait>().count(), 1);
+}
+
+#[derive(Resource, Default)]
+struct Numbered<const I: i32>;
+
+impl<const I: i32> IncrementTrait for Numbered<I> {
+    fn value(&self) -> i32 {
+        I
+    }
+    fn increment(&mut self) {}
+}
+
+fn register_numbered<const I: i32>(world: &mut World) {
+    world.init_resource::<Numbered<I>>();
+    world.register_resource_as::<dyn IncrementTrait, Numbered<I>>();
+}
+
+#[test]
+fn registry_order_survives_unregistering() {
+    let mut world = World::new();
+    register_numbered::<0>(&mut world);
+    register_numbered::<1>(&mut world);
+    register_numbered::<2>(&mut world);
+    register_numbered::<3>(&mut world);
+    register_numbered::<4>(&mut world);
+    register_numbered::<5>(&mut world);
+
+    let values = |world: &World| -> Vec<i32> { world.get_resources_trait::<dyn IncrementTrait>().flatten().map(|res| res.value()).collect() };
+
+    world.unregister_resource_from_trait::<dyn IncrementTrait, Numbered<1>>();
+    world.unregister_resource_from_trait::<dyn IncrementTrait, Numbered<3>>();
+    assert_eq!(values(&world), vec![0, 2, 4, 5]);
+
+    // Compacts the registry.
+    world.unregister_resource_from_trait::<dyn IncrementTrait, Numbered<4>>();
+    assert_eq!(values(&world), vec![0, 2, 5]);
+
+    world.register_resource_as::<dyn IncrementTrait, Numbered<1>>();
+    world.set_trait_resource_enabled::<dyn IncrementTrait, Numbered<2>>(false);
+    assert_eq!(values(&world), vec![0, 5, 1]);
+
+    // Re-registering keeps the position of the registration.
+    world.register_resource_as::<dyn IncrementTrait, Numbered<0>>();
+    world.set_trait_resource_enabled::<dyn IncrementTrait, Numbered<2>>(true);
+    assert_eq!(values(&world), vec![0, 2, 5, 1]);
+
+    let handle = world.trait_resource_handle::<dyn IncrementTrait, Numbered<5>>().unwrap();
+    assert_eq!(world.get_by_handle(&handle).map(|res| res.value()), Some(5));
+}
@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait LoadTrait {
+    async fn load(&self) -> u32;
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait LendTrait {
+    type Item<'a>;
+    fn lend(&self) -> u32;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait VisitTrait {
+    fn visit<T>(&self, value: T);
+}
+
+fn main() {}
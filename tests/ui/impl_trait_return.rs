@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait ValuesTrait {
+    fn values(&self) -> impl Iterator<Item = u32>;
+}
+
+fn main() {}
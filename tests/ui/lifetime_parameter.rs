@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait BorrowTrait<'a> {
+    fn borrowed(&self) -> &'a str;
+}
+
+fn main() {}
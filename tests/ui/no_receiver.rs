@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait NewTrait {
+    fn create() -> u32;
+}
+
+fn main() {}
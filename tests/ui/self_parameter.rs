@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait CompareTrait {
+    fn same(&self, other: &Self) -> bool;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use bevy_trait_resource::trait_resource;
+
+#[trait_resource]
+pub trait SizedTrait: Sized {
+    fn value(&self) -> u32;
+}
+
+fn main() {}